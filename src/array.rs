@@ -0,0 +1,201 @@
+use crate::{mode, Error, Hdc20xx, Measurement, SlaveAddr, MAX_DATA_READY_POLLS};
+use embedded_hal::{delay::DelayNs, i2c::I2c};
+
+/// I²C multiplexer channel selection
+///
+/// This is implemented by [`Tca9548a`](struct.Tca9548a.html) and can be
+/// implemented for other multiplexers.
+pub trait ChannelSelector {
+    /// Error type
+    type Error;
+
+    /// Connect the given downstream channel to the bus.
+    fn select(&mut self, channel: u8) -> Result<(), Self::Error>;
+}
+
+/// Errors of a single sensor in a [`SensorArray`](struct.SensorArray.html)
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SensorArrayError<E, SE> {
+    /// Error communicating with the sensor
+    Sensor(Error<E>),
+    /// Error selecting the multiplexer channel of the sensor
+    Selector(SE),
+    /// There is no sensor at the given channel and address
+    UnknownSensor,
+}
+
+/// Measurement result of a single sensor in a [`SensorArray`](struct.SensorArray.html)
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ArrayMeasurement<E, SE> {
+    /// Multiplexer channel of the sensor
+    pub channel: u8,
+    /// Address of the sensor
+    pub address: SlaveAddr,
+    /// Measurement or error of the sensor
+    pub result: Result<Measurement, SensorArrayError<E, SE>>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct ArraySensor<I2C> {
+    channel: u8,
+    address: SlaveAddr,
+    driver: Hdc20xx<I2C, mode::OneShot>,
+}
+
+/// Array of devices behind an I²C multiplexer
+///
+/// Each device is identified by its multiplexer channel and its address.
+/// The multiplexer and the devices share the bus, for example through the
+/// bus sharing implementations of `embedded-hal-bus`.
+///
+/// Errors are reported per device so that a failing device does not prevent
+/// the others from being measured.
+///
+/// ```no_run
+/// use core::cell::RefCell;
+/// use embedded_hal_bus::i2c::RefCellDevice;
+/// use hdc20xx::{SensorArray, SlaveAddr, Tca9548a};
+/// use linux_embedded_hal::{Delay, I2cdev};
+///
+/// let bus = RefCell::new(I2cdev::new("/dev/i2c-1").unwrap());
+/// let mux = Tca9548a::new(RefCellDevice::new(&bus), 0x70);
+/// let locations = [(0, SlaveAddr::default()), (1, SlaveAddr::default())];
+/// let mut array = SensorArray::new(mux, locations, || RefCellDevice::new(&bus));
+/// for data in array.measure_all(&mut Delay {}).iter() {
+///     match &data.result {
+///         Ok(m) => println!("{}: {:2}°C", data.channel, m.temperature),
+///         Err(e) => println!("{}: Error {:?}", data.channel, e),
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SensorArray<I2C, S, const N: usize> {
+    selector: S,
+    sensors: [ArraySensor<I2C>; N],
+}
+
+impl<I2C, S, const N: usize> SensorArray<I2C, S, N> {
+    /// Create new instance of the array.
+    ///
+    /// A device is created for each `(channel, address)` location with the
+    /// bus returned by `bus`.
+    pub fn new<F>(selector: S, locations: [(u8, SlaveAddr); N], mut bus: F) -> Self
+    where
+        F: FnMut() -> I2C,
+    {
+        SensorArray {
+            selector,
+            sensors: locations.map(|(channel, address)| ArraySensor {
+                channel,
+                address,
+                driver: Hdc20xx::new(bus(), address),
+            }),
+        }
+    }
+
+    /// Destroy the array and return the channel selector and the devices.
+    pub fn destroy(self) -> (S, [Hdc20xx<I2C, mode::OneShot>; N]) {
+        (self.selector, self.sensors.map(|sensor| sensor.driver))
+    }
+
+    /// Locations of the devices as `(channel, address)`
+    ///
+    /// These are in the same order as the results of
+    /// [`measure_all()`](#method.measure_all).
+    pub fn locations(&self) -> [(u8, SlaveAddr); N] {
+        core::array::from_fn(|i| (self.sensors[i].channel, self.sensors[i].address))
+    }
+}
+
+impl<I2C, E, S, const N: usize> SensorArray<I2C, S, N>
+where
+    I2C: I2c<Error = E>,
+    S: ChannelSelector,
+{
+    /// Select the multiplexer channel of a device and return it.
+    ///
+    /// This can be used to configure a device. Note that the multiplexer
+    /// channel must not be changed while the device is in use.
+    pub fn select(
+        &mut self,
+        channel: u8,
+        address: SlaveAddr,
+    ) -> Result<&mut Hdc20xx<I2C, mode::OneShot>, SensorArrayError<E, S::Error>> {
+        let sensor = self
+            .sensors
+            .iter_mut()
+            .find(|s| s.channel == channel && s.address.addr() == address.addr())
+            .ok_or(SensorArrayError::UnknownSensor)?;
+        self.selector
+            .select(channel)
+            .map_err(SensorArrayError::Selector)?;
+        Ok(&mut sensor.driver)
+    }
+
+    /// Measure with all devices.
+    ///
+    /// All measurements are triggered in a first pass so that the devices
+    /// convert in parallel. After waiting for the longest conversion time,
+    /// the results are collected in a second pass. A device failing does not
+    /// prevent the others from being measured.
+    pub fn measure_all<D: DelayNs>(&mut self, delay: &mut D) -> [ArrayMeasurement<E, S::Error>; N] {
+        let SensorArray { selector, sensors } = self;
+        let mut triggered: [Result<(), SensorArrayError<E, S::Error>>; N] =
+            core::array::from_fn(|i| {
+                let sensor = &mut sensors[i];
+                selector
+                    .select(sensor.channel)
+                    .map_err(SensorArrayError::Selector)?;
+                sensor
+                    .driver
+                    .trigger_measurement()
+                    .map_err(SensorArrayError::Sensor)
+            });
+        let conversion_time_us = sensors
+            .iter()
+            .map(|sensor| sensor.driver.conversion_time_us())
+            .max()
+            .unwrap_or(0);
+        delay.delay_us(conversion_time_us);
+        core::array::from_fn(|i| {
+            let sensor = &mut sensors[i];
+            let result = core::mem::replace(&mut triggered[i], Ok(())).and_then(|_| {
+                selector
+                    .select(sensor.channel)
+                    .map_err(SensorArrayError::Selector)?;
+                collect(&mut sensor.driver, delay).map_err(SensorArrayError::Sensor)
+            });
+            if result.is_err() {
+                sensor.driver.was_measurement_started = false;
+            }
+            ArrayMeasurement {
+                channel: sensor.channel,
+                address: sensor.address,
+                result,
+            }
+        })
+    }
+}
+
+/// Read a triggered measurement, waiting if it is not available yet.
+fn collect<I2C, E, D>(
+    driver: &mut Hdc20xx<I2C, mode::OneShot>,
+    delay: &mut D,
+) -> Result<Measurement, Error<E>>
+where
+    I2C: I2c<Error = E>,
+    D: DelayNs,
+{
+    for _ in 0..MAX_DATA_READY_POLLS {
+        match driver.read() {
+            Ok(measurement) => return Ok(measurement),
+            Err(nb::Error::Other(e)) => return Err(e),
+            Err(nb::Error::WouldBlock) => delay.delay_us(driver.conversion_time_us()),
+        }
+    }
+    Err(Error::Timeout)
+}
@@ -0,0 +1,236 @@
+use crate::{
+    conversion, BitFlags, Config, Error, InterruptConfig, Measurement, MeasurementMode, Register,
+    Resolution, SlaveAddr, Status, MAX_DATA_READY_POLLS,
+};
+use embedded_hal::digital::Error as _;
+use embedded_hal_async::{delay::DelayNs, digital::Wait, i2c::I2c};
+
+/// Asynchronous HDC2080, HDC2021 and HDC2010 device driver
+///
+/// This driver makes one-shot measurements. Only available with the
+/// `async` feature.
+///
+/// ```no_run
+/// # async fn example<I2C, D>(i2c: I2C, mut delay: D)
+/// # where
+/// #     I2C: embedded_hal_async::i2c::I2c,
+/// #     D: embedded_hal_async::delay::DelayNs,
+/// # {
+/// use hdc20xx::{AsyncHdc20xx, SlaveAddr};
+///
+/// let mut sensor = AsyncHdc20xx::new(i2c, SlaveAddr::default());
+/// let data = sensor.measure(&mut delay).await.unwrap();
+/// println!("Temperature: {:2}°C", data.temperature);
+/// # }
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AsyncHdc20xx<I2C> {
+    i2c: I2C,
+    address: u8,
+    meas_config: Config,
+    dev_config: Config,
+}
+
+impl<I2C> AsyncHdc20xx<I2C> {
+    /// Create new instance of the device.
+    pub fn new(i2c: I2C, address: SlaveAddr) -> Self {
+        AsyncHdc20xx {
+            i2c,
+            address: address.addr(),
+            meas_config: Config::default(),
+            dev_config: Config::default(),
+        }
+    }
+
+    /// Destroy driver instance, return I2C bus.
+    pub fn destroy(self) -> I2C {
+        self.i2c
+    }
+
+    /// Get the typical conversion time (µs) of a measurement with the
+    /// current measurement mode and resolution configuration.
+    pub fn conversion_time_us(&self) -> u32 {
+        self.meas_config.conversion_time_us()
+    }
+}
+
+impl<I2C, E> AsyncHdc20xx<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Set measurement mode
+    pub async fn set_measurement_mode(&mut self, mode: MeasurementMode) -> Result<(), Error<E>> {
+        let config = match mode {
+            MeasurementMode::TemperatureAndHumidity => {
+                self.meas_config.with_low(BitFlags::TEMP_ONLY)
+            }
+            MeasurementMode::TemperatureOnly => self.meas_config.with_high(BitFlags::TEMP_ONLY),
+        };
+        self.write_register(Register::MEAS_CONF, config.bits)
+            .await?;
+        self.meas_config = config;
+        Ok(())
+    }
+
+    /// Set temperature resolution
+    pub async fn set_temperature_resolution(
+        &mut self,
+        resolution: Resolution,
+    ) -> Result<(), Error<E>> {
+        let config = self
+            .meas_config
+            .with_low(BitFlags::TRES_MASK)
+            .with_high(resolution.bits() << 6);
+        self.write_register(Register::MEAS_CONF, config.bits)
+            .await?;
+        self.meas_config = config;
+        Ok(())
+    }
+
+    /// Set humidity resolution
+    pub async fn set_humidity_resolution(
+        &mut self,
+        resolution: Resolution,
+    ) -> Result<(), Error<E>> {
+        let config = self
+            .meas_config
+            .with_low(BitFlags::HRES_MASK)
+            .with_high(resolution.bits() << 4);
+        self.write_register(Register::MEAS_CONF, config.bits)
+            .await?;
+        self.meas_config = config;
+        Ok(())
+    }
+
+    /// Make a measurement of temperature or temperature and humidity
+    /// according to the configuration.
+    ///
+    /// This triggers the measurement, waits for the conversion time and
+    /// reads the data once it is available. `Error::Timeout` is returned if
+    /// the data is not ready after 10 status reads.
+    pub async fn measure<D: DelayNs>(&mut self, delay: &mut D) -> Result<Measurement, Error<E>> {
+        let meas_conf = self.meas_config.with_high(BitFlags::MEAS_TRIG);
+        self.write_register(Register::MEAS_CONF, meas_conf.bits)
+            .await?;
+        for _ in 0..MAX_DATA_READY_POLLS {
+            delay.delay_us(self.conversion_time_us()).await;
+            let status = self.status().await?;
+            if status.data_ready {
+                return self.read_measurement(status).await;
+            }
+        }
+        Err(Error::Timeout)
+    }
+
+    /// Make a measurement of temperature or temperature and humidity
+    /// according to the configuration using the interrupt pin to detect
+    /// when the data is ready.
+    ///
+    /// The data ready interrupt must be enabled with
+    /// [`set_interrupt_config()`](#method.set_interrupt_config) and `pin`
+    /// must be connected to the interrupt pin of the device.
+    ///
+    /// This triggers the measurement and waits until the pin is active.
+    /// Then the data and the status are read in a single transaction, which
+    /// also deasserts the interrupt. If the status does not report that data
+    /// is ready, the pin was active due to another interrupt source or an
+    /// earlier measurement and it is waited for again.
+    pub async fn measure_with_drdy_pin<P: Wait>(
+        &mut self,
+        pin: &mut P,
+    ) -> Result<Measurement, Error<E>> {
+        let meas_conf = self.meas_config.with_high(BitFlags::MEAS_TRIG);
+        self.write_register(Register::MEAS_CONF, meas_conf.bits)
+            .await?;
+        loop {
+            let result = if self.dev_config.is_high(BitFlags::INT_POL) {
+                pin.wait_for_high().await
+            } else {
+                pin.wait_for_low().await
+            };
+            result.map_err(|e| Error::Pin(e.kind()))?;
+            let mut data = [0; 5];
+            self.read_data(Register::TEMP_L, &mut data).await?;
+            let status = conversion::status_from_register(data[4]);
+            if !status.data_ready {
+                continue;
+            }
+            let values = if self.meas_config.includes_humidity() {
+                &data[..4]
+            } else {
+                &data[..2]
+            };
+            return Ok(Measurement::from(conversion::raw_measurement_from_data(
+                values, status,
+            )));
+        }
+    }
+
+    /// Set the interrupt configuration.
+    ///
+    /// This enables the given interrupt sources and configures the
+    /// interrupt pin polarity and mode. The interrupt pin is enabled if
+    /// any source is enabled and set to high impedance otherwise.
+    pub async fn set_interrupt_config(&mut self, config: InterruptConfig) -> Result<(), Error<E>> {
+        self.write_register(Register::INTERRUPT_ENABLE, config.enable_bits())
+            .await?;
+        let dev_config = self.dev_config.with_interrupt_config(config);
+        self.write_register(Register::DEVICE_CONFIG, dev_config.bits)
+            .await?;
+        self.dev_config = dev_config;
+        Ok(())
+    }
+
+    /// Read data and interrupt status
+    pub async fn status(&mut self) -> Result<Status, Error<E>> {
+        let mut data = [0];
+        self.read_data(Register::DRDY, &mut data).await?;
+        trace!("DRDY status: {=u8:#04x}", data[0]);
+        Ok(conversion::status_from_register(data[0]))
+    }
+
+    /// Get device ID
+    pub async fn device_id(&mut self) -> Result<u16, Error<E>> {
+        self.read_double_register(Register::DEVICE_ID_L).await
+    }
+
+    /// Get manufacturer ID
+    pub async fn manufacturer_id(&mut self) -> Result<u16, Error<E>> {
+        self.read_double_register(Register::MANUFACTURER_ID_L).await
+    }
+
+    async fn read_measurement(&mut self, status: Status) -> Result<Measurement, Error<E>> {
+        let mut data = [0; 4];
+        let data = if self.meas_config.includes_humidity() {
+            &mut data[..]
+        } else {
+            &mut data[..2]
+        };
+        self.read_data(Register::TEMP_L, data).await?;
+        Ok(Measurement::from(conversion::raw_measurement_from_data(
+            data, status,
+        )))
+    }
+
+    async fn write_register(&mut self, register: u8, data: u8) -> Result<(), Error<E>> {
+        trace!("write register {=u8:#04x}: {=u8:#04x}", register, data);
+        self.i2c
+            .write(self.address, &[register, data])
+            .await
+            .map_err(Error::I2C)
+    }
+
+    async fn read_double_register(&mut self, register: u8) -> Result<u16, Error<E>> {
+        let mut data = [0, 0];
+        self.read_data(register, &mut data).await?;
+        Ok(u16::from(data[0]) | (u16::from(data[1]) << 8))
+    }
+
+    async fn read_data(&mut self, register: u8, data: &mut [u8]) -> Result<(), Error<E>> {
+        self.i2c
+            .write_read(self.address, &[register], data)
+            .await
+            .map_err(Error::I2C)
+    }
+}
@@ -0,0 +1,383 @@
+//! Command-line tool for HDC20xx devices connected to a Linux `i2c-dev` bus.
+//!
+//! Build with `cargo build --features cli` and run `hdc20xx --help`.
+
+// The tool follows the minimum Rust version of its dependencies, which is
+// higher than the one of the library.
+#![allow(clippy::incompatible_msrv)]
+
+use clap::{Parser, Subcommand, ValueEnum};
+use embedded_hal::{delay::DelayNs, i2c::I2c};
+use hdc20xx::{mode, Hdc20xx, Measurement, MeasurementMode, RegisterDump, Resolution, SlaveAddr};
+use linux_embedded_hal::{Delay, I2cdev};
+use serde::Serialize;
+use std::{
+    fmt::Debug,
+    process::ExitCode,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Read and configure HDC2080, HDC2010 and HDC2021 sensors.
+#[derive(Debug, Parser)]
+#[command(version)]
+struct Cli {
+    /// I²C bus device
+    #[arg(short, long, default_value = "/dev/i2c-1")]
+    bus: String,
+
+    /// Device address (0x40 or 0x41)
+    #[arg(short, long, default_value = "0x40", value_parser = parse_address)]
+    address: SlaveAddr,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Format::Human)]
+    format: Format,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Make a single measurement.
+    Read,
+    /// Measure periodically.
+    Watch {
+        /// Time between measurements (ms)
+        #[arg(short, long, default_value_t = 1000)]
+        interval: u32,
+
+        /// Number of measurements. Runs until interrupted if not given.
+        #[arg(short, long)]
+        count: Option<u64>,
+    },
+    /// Change the device configuration.
+    ///
+    /// The tool uses the one-shot mode, so changing the heater setting stops
+    /// the auto measurement mode.
+    Config(ConfigArgs),
+    /// Print the contents of all registers.
+    DumpRegisters,
+    /// Print the manufacturer and device ID.
+    Identify,
+}
+
+#[derive(Debug, clap::Args)]
+struct ConfigArgs {
+    /// Measured quantities. This is not the auto measurement mode.
+    #[arg(long, value_enum)]
+    measurement_mode: Option<Mode>,
+
+    /// Temperature resolution (bits)
+    #[arg(long, value_enum)]
+    temperature_resolution: Option<Bits>,
+
+    /// Humidity resolution (bits)
+    #[arg(long, value_enum)]
+    humidity_resolution: Option<Bits>,
+
+    /// Temperature offset adjustment (°C)
+    #[arg(long, allow_hyphen_values = true)]
+    temperature_offset: Option<f32>,
+
+    /// Humidity offset adjustment (%RH)
+    #[arg(long, allow_hyphen_values = true)]
+    humidity_offset: Option<f32>,
+
+    /// Temperature low threshold (°C)
+    #[arg(long, allow_hyphen_values = true)]
+    temperature_threshold_low: Option<f32>,
+
+    /// Temperature high threshold (°C)
+    #[arg(long, allow_hyphen_values = true)]
+    temperature_threshold_high: Option<f32>,
+
+    /// Humidity low threshold (%RH)
+    #[arg(long)]
+    humidity_threshold_low: Option<f32>,
+
+    /// Humidity high threshold (%RH)
+    #[arg(long)]
+    humidity_threshold_high: Option<f32>,
+
+    /// Integrated heater
+    #[arg(long, value_enum)]
+    heater: Option<Switch>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+enum Format {
+    /// Human-readable text
+    Human,
+    /// Comma-separated values with a header line
+    Csv,
+    /// One JSON object per line
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Mode {
+    TemperatureAndHumidity,
+    TemperatureOnly,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Bits {
+    #[value(name = "14")]
+    Bits14,
+    #[value(name = "11")]
+    Bits11,
+    #[value(name = "9")]
+    Bits9,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Switch {
+    On,
+    Off,
+}
+
+impl From<Mode> for MeasurementMode {
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::TemperatureAndHumidity => MeasurementMode::TemperatureAndHumidity,
+            Mode::TemperatureOnly => MeasurementMode::TemperatureOnly,
+        }
+    }
+}
+
+impl From<Bits> for Resolution {
+    fn from(bits: Bits) -> Self {
+        match bits {
+            Bits::Bits14 => Resolution::Bits14,
+            Bits::Bits11 => Resolution::Bits11,
+            Bits::Bits9 => Resolution::Bits9,
+        }
+    }
+}
+
+fn parse_address(value: &str) -> Result<SlaveAddr, String> {
+    let digits = value.trim_start_matches("0x").trim_start_matches("0X");
+    match u8::from_str_radix(digits, 16) {
+        Ok(0x40) => Ok(SlaveAddr::Default),
+        Ok(0x41) => Ok(SlaveAddr::Alternative(true)),
+        _ => Err(format!(
+            "invalid address '{}', expected 0x40 or 0x41",
+            value
+        )),
+    }
+}
+
+/// Measurement with the time it was made
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    /// Seconds since the Unix epoch
+    timestamp: f64,
+    #[serde(flatten)]
+    measurement: &'a Measurement,
+}
+
+/// Register name and contents
+#[derive(Debug, Serialize)]
+struct RegisterValue {
+    register: u8,
+    name: &'static str,
+    value: u8,
+}
+
+#[derive(Debug, Serialize)]
+struct Identification {
+    manufacturer_id: u16,
+    device_id: u16,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let i2c = match I2cdev::new(&cli.bus) {
+        Ok(i2c) => i2c,
+        Err(e) => {
+            eprintln!("Error opening {}: {}", cli.bus, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    match run(&cli, i2c, &mut Delay {}) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run<I2C, E, D>(cli: &Cli, i2c: I2C, delay: &mut D) -> Result<(), String>
+where
+    I2C: I2c<Error = E>,
+    E: Debug,
+    D: DelayNs,
+{
+    let mut sensor = Hdc20xx::new(i2c, cli.address);
+    if let Command::Read | Command::Watch { .. } | Command::Config(_) = cli.command {
+        // Continue with the configuration already present in the device.
+        sensor.load_configuration().map_err(describe)?;
+    }
+    match &cli.command {
+        Command::Read => {
+            let data = sensor.measure(delay).map_err(describe)?;
+            print_measurement_header(cli.format);
+            print_measurement(cli.format, &data);
+        }
+        Command::Watch { interval, count } => {
+            print_measurement_header(cli.format);
+            let mut remaining = *count;
+            while remaining != Some(0) {
+                match sensor.measure(delay) {
+                    Ok(data) => print_measurement(cli.format, &data),
+                    Err(e) => eprintln!("Error: {}", describe(e)),
+                }
+                remaining = remaining.map(|n| n - 1);
+                if remaining != Some(0) {
+                    delay.delay_ms(*interval);
+                }
+            }
+        }
+        Command::Config(args) => configure(&mut sensor, args).map_err(describe)?,
+        Command::DumpRegisters => {
+            let dump = sensor.dump_registers().map_err(describe)?;
+            print_registers(cli.format, &dump);
+        }
+        Command::Identify => {
+            let id = Identification {
+                manufacturer_id: sensor.manufacturer_id().map_err(describe)?,
+                device_id: sensor.device_id().map_err(describe)?,
+            };
+            print_identification(cli.format, &id);
+        }
+    }
+    Ok(())
+}
+
+fn configure<I2C, E>(
+    sensor: &mut Hdc20xx<I2C, mode::OneShot>,
+    args: &ConfigArgs,
+) -> Result<(), hdc20xx::Error<E>>
+where
+    I2C: I2c<Error = E>,
+{
+    if let Some(mode) = args.measurement_mode {
+        sensor.set_measurement_mode(mode.into())?;
+    }
+    if let Some(bits) = args.temperature_resolution {
+        sensor.set_temperature_resolution(bits.into())?;
+    }
+    if let Some(bits) = args.humidity_resolution {
+        sensor.set_humidity_resolution(bits.into())?;
+    }
+    if let Some(offset) = args.temperature_offset {
+        sensor.set_temperature_offset(offset)?;
+    }
+    if let Some(offset) = args.humidity_offset {
+        sensor.set_humidity_offset(offset)?;
+    }
+    if let Some(threshold) = args.temperature_threshold_low {
+        sensor.set_temperature_threshold_low(threshold)?;
+    }
+    if let Some(threshold) = args.temperature_threshold_high {
+        sensor.set_temperature_threshold_high(threshold)?;
+    }
+    if let Some(threshold) = args.humidity_threshold_low {
+        sensor.set_humidity_threshold_low(threshold)?;
+    }
+    if let Some(threshold) = args.humidity_threshold_high {
+        sensor.set_humidity_threshold_high(threshold)?;
+    }
+    match args.heater {
+        Some(Switch::On) => sensor.enable_heater()?,
+        Some(Switch::Off) => sensor.disable_heater()?,
+        None => (),
+    }
+    Ok(())
+}
+
+fn describe<E: Debug>(error: hdc20xx::Error<E>) -> String {
+    match error {
+        hdc20xx::Error::InvalidInputData => "Value out of range".to_string(),
+        hdc20xx::Error::Timeout => "Timed out waiting for the measurement".to_string(),
+        e => format!("{:?}", e),
+    }
+}
+
+fn print_json<T: Serialize>(value: &T) {
+    println!(
+        "{}",
+        serde_json::to_string(value).expect("serializing to JSON cannot fail")
+    );
+}
+
+fn print_measurement_header(format: Format) {
+    if format == Format::Csv {
+        println!("timestamp,temperature,humidity");
+    }
+}
+
+fn print_measurement(format: Format, measurement: &Measurement) {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|t| t.as_secs_f64())
+        .unwrap_or(0.0);
+    match format {
+        Format::Human => match measurement.humidity {
+            Some(humidity) => println!(
+                "Temperature: {:.2}°C, Humidity: {:.2}%RH",
+                measurement.temperature, humidity
+            ),
+            None => println!("Temperature: {:.2}°C", measurement.temperature),
+        },
+        Format::Csv => println!(
+            "{:.3},{:.2},{}",
+            timestamp,
+            measurement.temperature,
+            measurement
+                .humidity
+                .map(|h| format!("{:.2}", h))
+                .unwrap_or_default()
+        ),
+        Format::Json => print_json(&Record {
+            timestamp,
+            measurement,
+        }),
+    }
+}
+
+fn print_registers(format: Format, dump: &RegisterDump) {
+    if format == Format::Human {
+        print!("{}", dump);
+        return;
+    }
+    if format == Format::Csv {
+        println!("register,name,value");
+    }
+    for &(register, name, value) in dump.named_registers().iter() {
+        match format {
+            Format::Csv => println!("0x{:02X},{},0x{:02X}", register, name, value),
+            _ => print_json(&RegisterValue {
+                register,
+                name,
+                value,
+            }),
+        }
+    }
+}
+
+fn print_identification(format: Format, id: &Identification) {
+    match format {
+        Format::Human => println!(
+            "Manufacturer ID: 0x{:04X}\nDevice ID: 0x{:04X}",
+            id.manufacturer_id, id.device_id
+        ),
+        Format::Csv => println!(
+            "manufacturer_id,device_id\n0x{:04X},0x{:04X}",
+            id.manufacturer_id, id.device_id
+        ),
+        Format::Json => print_json(id),
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{BitFlags, RawMeasurement, Status};
+
+const TEMP_RANGE: f32 = 165.0;
+const TEMP_OFFSET: f32 = -40.0;
+const RH_RANGE: f32 = 100.0;
+
+pub(crate) fn temperature_from_raw(raw: u16) -> f32 {
+    f32::from(raw) / 65536.0 * TEMP_RANGE + TEMP_OFFSET
+}
+
+pub(crate) fn humidity_from_raw(raw: u16) -> f32 {
+    f32::from(raw) / 65536.0 * RH_RANGE
+}
+
+/// Temperature in milli-degrees Celsius computed with integer arithmetic only.
+///
+/// `165000 / 65536` is reduced to `20625 / 8192`, which keeps the product
+/// within `i32` for all codes. The result is rounded to nearest.
+pub(crate) fn temperature_milli_from_raw(raw: u16) -> i32 {
+    ((i32::from(raw) * 20625 + 4096) >> 13) - 40_000
+}
+
+/// Relative humidity in milli-percent computed with integer arithmetic only.
+///
+/// `100000 / 65536` is reduced to `3125 / 2048`. The result is rounded to
+/// nearest.
+pub(crate) fn humidity_milli_from_raw(raw: u16) -> u32 {
+    (u32::from(raw) * 3125 + 1024) >> 11
+}
+
+/// Status from the `DRDY` register contents
+pub(crate) fn status_from_register(status: u8) -> Status {
+    Status {
+        data_ready: (status & BitFlags::DRDY_STATUS) != 0,
+        high_temp_threshold_exceeded: (status & BitFlags::TH_STATUS) != 0,
+        low_temp_threshold_exceeded: (status & BitFlags::TL_STATUS) != 0,
+        high_humidity_threshold_exceeded: (status & BitFlags::HH_STATUS) != 0,
+        low_humidity_threshold_exceeded: (status & BitFlags::HL_STATUS) != 0,
+    }
+}
+
+/// Raw measurement from the data registers contents starting at `TEMP_L`.
+///
+/// Humidity is only included if `data` contains the humidity registers.
+pub(crate) fn raw_measurement_from_data(data: &[u8], status: Status) -> RawMeasurement {
+    let humidity = if data.len() >= 4 {
+        Some(u16::from(data[2]) | (u16::from(data[3]) << 8))
+    } else {
+        None
+    };
+    RawMeasurement {
+        temperature: u16::from(data[0]) | (u16::from(data[1]) << 8),
+        humidity,
+        status,
+    }
+}
+
+/// Temperature from an 8-bit register (threshold registers)
+pub(crate) fn temperature_from_u8(code: u8) -> f32 {
+    f32::from(code) / 256.0 * TEMP_RANGE + TEMP_OFFSET
+}
+
+/// Relative humidity from an 8-bit register (threshold registers)
+pub(crate) fn humidity_from_u8(code: u8) -> f32 {
+    f32::from(code) / 256.0 * RH_RANGE
+}
+
+/// Nearest 8-bit register code for a temperature. `None` if out of range.
+pub(crate) fn temperature_to_u8(temperature: f32) -> Option<u8> {
+    if !(TEMP_OFFSET..=temperature_from_u8(255)).contains(&temperature) {
+        return None;
+    }
+    Some(((temperature - TEMP_OFFSET) / TEMP_RANGE * 256.0 + 0.5) as u8)
+}
+
+/// Nearest 8-bit register code for a relative humidity. `None` if out of range.
+pub(crate) fn humidity_to_u8(humidity: f32) -> Option<u8> {
+    if !(0.0..=humidity_from_u8(255)).contains(&humidity) {
+        return None;
+    }
+    Some((humidity / RH_RANGE * 256.0 + 0.5) as u8)
+}
+
+/// Temperature offset adjustment register weight (°C)
+const TEMP_OFFSET_LSB: f32 = TEMP_RANGE / 1024.0;
+/// Humidity offset adjustment register weight (%RH)
+const RH_OFFSET_LSB: f32 = RH_RANGE / 512.0;
+
+pub(crate) fn temperature_offset_from_code(code: u8) -> f32 {
+    f32::from(code as i8) * TEMP_OFFSET_LSB
+}
+
+pub(crate) fn humidity_offset_from_code(code: u8) -> f32 {
+    f32::from(code as i8) * RH_OFFSET_LSB
+}
+
+/// Nearest offset adjustment register code for a temperature offset.
+/// `None` if out of range.
+pub(crate) fn temperature_offset_to_code(offset: f32) -> Option<u8> {
+    offset_to_code(offset, TEMP_OFFSET_LSB)
+}
+
+/// Nearest offset adjustment register code for a humidity offset.
+/// `None` if out of range.
+pub(crate) fn humidity_offset_to_code(offset: f32) -> Option<u8> {
+    offset_to_code(offset, RH_OFFSET_LSB)
+}
+
+fn offset_to_code(offset: f32, lsb: f32) -> Option<u8> {
+    let min = f32::from(i8::MIN) * lsb;
+    let max = f32::from(i8::MAX) * lsb;
+    if !(min..=max).contains(&offset) {
+        return None;
+    }
+    let steps = offset / lsb;
+    let code = if steps < 0.0 {
+        (steps - 0.5) as i8
+    } else {
+        (steps + 0.5) as i8
+    };
+    Some(code as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_point_temperature_matches_float_for_all_codes() {
+        for raw in 0..=u16::MAX {
+            let expected = f64::from(temperature_from_raw(raw)) * 1000.0;
+            let actual = f64::from(temperature_milli_from_raw(raw));
+            assert!((actual - expected).abs() <= 1.0, "code {}", raw);
+        }
+    }
+
+    #[test]
+    fn fixed_point_humidity_matches_float_for_all_codes() {
+        for raw in 0..=u16::MAX {
+            let expected = f64::from(humidity_from_raw(raw)) * 1000.0;
+            let actual = f64::from(humidity_milli_from_raw(raw));
+            assert!((actual - expected).abs() <= 1.0, "code {}", raw);
+        }
+    }
+
+    #[test]
+    fn can_convert_fixed_point_limits() {
+        assert_eq!(-40_000, temperature_milli_from_raw(0));
+        assert_eq!(124_997, temperature_milli_from_raw(u16::MAX));
+        assert_eq!(0, humidity_milli_from_raw(0));
+        assert_eq!(99_998, humidity_milli_from_raw(u16::MAX));
+    }
+
+    #[test]
+    fn can_convert_temperature_u8_round_trip() {
+        for code in 0..=255 {
+            assert_eq!(Some(code), temperature_to_u8(temperature_from_u8(code)));
+        }
+    }
+
+    #[test]
+    fn can_convert_humidity_u8_round_trip() {
+        for code in 0..=255 {
+            assert_eq!(Some(code), humidity_to_u8(humidity_from_u8(code)));
+        }
+    }
+
+    #[test]
+    fn can_convert_offset_round_trip() {
+        for code in 0..=255 {
+            let temp = temperature_offset_from_code(code);
+            assert_eq!(Some(code), temperature_offset_to_code(temp));
+            let rh = humidity_offset_from_code(code);
+            assert_eq!(Some(code), humidity_offset_to_code(rh));
+        }
+    }
+
+    #[test]
+    fn can_convert_offset_weighted_bits() {
+        assert_eq!(Some(0x80), temperature_offset_to_code(-20.625));
+        assert_eq!(Some(0x01), temperature_offset_to_code(0.16));
+        assert_eq!(Some(0xFF), temperature_offset_to_code(-0.16));
+        assert_eq!(Some(0x80), humidity_offset_to_code(-25.0));
+        assert_eq!(Some(0x40), humidity_offset_to_code(12.5));
+        assert_eq!(Some(0x01), humidity_offset_to_code(0.2));
+    }
+
+    #[test]
+    fn rejects_out_of_range_offsets() {
+        assert_eq!(None, temperature_offset_to_code(-20.7));
+        assert_eq!(None, temperature_offset_to_code(20.5));
+        assert_eq!(None, temperature_offset_to_code(f32::NAN));
+        assert_eq!(None, humidity_offset_to_code(-25.1));
+        assert_eq!(None, humidity_offset_to_code(24.9));
+        assert_eq!(None, humidity_offset_to_code(f32::NAN));
+    }
+
+    #[test]
+    fn rejects_out_of_range_u8_values() {
+        assert_eq!(None, temperature_to_u8(-40.1));
+        assert_eq!(None, temperature_to_u8(124.4));
+        assert_eq!(None, temperature_to_u8(f32::NAN));
+        assert_eq!(None, humidity_to_u8(-0.1));
+        assert_eq!(None, humidity_to_u8(99.7));
+        assert_eq!(None, humidity_to_u8(f32::NAN));
+    }
+}
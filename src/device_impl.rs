@@ -1,9 +1,19 @@
 use crate::{
-    mode, BitFlags, Config, Error, Hdc20xx, Measurement, MeasurementMode, Register, SlaveAddr,
-    Status,
+    conversion, mode, private, BitFlags, Config, DeviceConfiguration, Error, Hdc20xx,
+    InterruptConfig, InterruptMode, InterruptPolarity, Measurement, MeasurementMode,
+    ModeChangeError, RawMeasurement, Register, RegisterDump, Resolution, SamplingRate, SlaveAddr,
+    Status, Variant, DEVICE_ID, MANUFACTURER_ID, MAX_DATA_READY_POLLS, MAX_RESET_POLLS,
+    RESET_POLL_INTERVAL_US,
 };
 use core::marker::PhantomData;
-use embedded_hal::blocking::i2c;
+use embedded_hal::{
+    delay::DelayNs,
+    digital::{Error as _, InputPin},
+    i2c::I2c,
+};
+
+/// Maximum heater duration accepted by `clear_condensation()` (ms)
+const MAX_HEATER_DURATION_MS: u16 = 10_000;
 
 impl<I2C> Hdc20xx<I2C, mode::OneShot> {
     /// Create new instance of the device.
@@ -12,22 +22,93 @@ impl<I2C> Hdc20xx<I2C, mode::OneShot> {
             i2c,
             address: address.addr(),
             meas_config: Config { bits: 0 },
+            dev_config: Config { bits: 0 },
             was_measurement_started: false,
+            read_peaks: false,
+            variant: None,
             _mode: PhantomData,
         }
     }
 }
 
+impl<I2C, E> Hdc20xx<I2C, mode::OneShot>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the device and verify its identity.
+    ///
+    /// This reads the manufacturer and device ID and returns
+    /// `Error::UnexpectedDeviceId` if they do not match the expected values.
+    ///
+    /// All variants report the same IDs so the variant cannot be detected
+    /// and must be provided. It can be retrieved later with
+    /// [`variant()`](#method.variant).
+    pub fn new_checked(i2c: I2C, address: SlaveAddr, variant: Variant) -> Result<Self, Error<E>> {
+        let mut dev = Self::new(i2c, address);
+        let manufacturer = dev.manufacturer_id()?;
+        let device = dev.device_id()?;
+        if manufacturer != MANUFACTURER_ID || device != DEVICE_ID {
+            return Err(Error::UnexpectedDeviceId {
+                manufacturer,
+                device,
+            });
+        }
+        dev.variant = Some(variant);
+        Ok(dev)
+    }
+}
+
 impl<I2C, MODE> Hdc20xx<I2C, MODE> {
     /// Destroy driver instance, return I2C bus.
     pub fn destroy(self) -> I2C {
         self.i2c
     }
+
+    /// Get the device variant.
+    ///
+    /// This is only available if the device was created with
+    /// [`new_checked()`](#method.new_checked).
+    pub fn variant(&self) -> Option<Variant> {
+        self.variant
+    }
+
+    /// Get the typical conversion time (µs) of a measurement with the
+    /// current measurement mode and resolution configuration.
+    ///
+    /// This can be used to size the delay between calls to `read()`.
+    pub fn conversion_time_us(&self) -> u32 {
+        self.meas_config.conversion_time_us()
+    }
+
+    fn with_mode<NEW: private::Sealed>(self) -> Hdc20xx<I2C, NEW> {
+        Hdc20xx {
+            i2c: self.i2c,
+            address: self.address,
+            meas_config: self.meas_config,
+            dev_config: self.dev_config,
+            was_measurement_started: false,
+            read_peaks: self.read_peaks,
+            variant: self.variant,
+            _mode: PhantomData,
+        }
+    }
+
+    /// Include the temperature and humidity peak values in every measurement.
+    ///
+    /// See [`max_temperature()`](#method.max_temperature) for the caveats.
+    pub fn enable_peak_readout(&mut self) {
+        self.read_peaks = true;
+    }
+
+    /// Do not include the peak values in the measurements (default).
+    pub fn disable_peak_readout(&mut self) {
+        self.read_peaks = false;
+    }
 }
 
 impl<I2C, E, MODE> Hdc20xx<I2C, MODE>
 where
-    I2C: i2c::WriteRead<Error = E> + i2c::Write<Error = E>,
+    I2C: I2c<Error = E>,
 {
     /// Set measurement mode
     pub fn set_measurement_mode(&mut self, mode: MeasurementMode) -> Result<(), Error<E>> {
@@ -42,16 +123,233 @@ where
         Ok(())
     }
 
+    /// Set temperature resolution
+    pub fn set_temperature_resolution(&mut self, resolution: Resolution) -> Result<(), Error<E>> {
+        let config = self
+            .meas_config
+            .with_low(BitFlags::TRES_MASK)
+            .with_high(resolution.bits() << 6);
+        self.write_register(Register::MEAS_CONF, config.bits)?;
+        self.meas_config = config;
+        Ok(())
+    }
+
+    /// Set humidity resolution
+    pub fn set_humidity_resolution(&mut self, resolution: Resolution) -> Result<(), Error<E>> {
+        let config = self
+            .meas_config
+            .with_low(BitFlags::HRES_MASK)
+            .with_high(resolution.bits() << 4);
+        self.write_register(Register::MEAS_CONF, config.bits)?;
+        self.meas_config = config;
+        Ok(())
+    }
+
     /// Read data and interrupt status
     pub fn status(&mut self) -> Result<Status, Error<E>> {
         let status = self.read_register(Register::DRDY)?;
-        Ok(Status {
-            data_ready: (status & BitFlags::DRDY_STATUS) != 0,
-            high_temp_threshold_exceeded: (status & BitFlags::TH_STATUS) != 0,
-            low_temp_threshold_exceeded: (status & BitFlags::TL_STATUS) != 0,
-            high_humidity_threshold_exceeded: (status & BitFlags::HH_STATUS) != 0,
-            low_humidity_threshold_exceeded: (status & BitFlags::HL_STATUS) != 0,
-        })
+        trace!("DRDY status: {=u8:#04x}", status);
+        Ok(conversion::status_from_register(status))
+    }
+
+    /// Set the temperature offset adjustment (°C).
+    ///
+    /// The offset is added to every temperature measurement by the device.
+    /// Valid range: [-20.62, 20.46]. The value is rounded to the nearest
+    /// step of ~0.16°C.
+    ///
+    /// Returns `Error::InvalidInputData` if the value is out of range.
+    pub fn set_temperature_offset(&mut self, offset: f32) -> Result<(), Error<E>> {
+        let code = conversion::temperature_offset_to_code(offset).ok_or(Error::InvalidInputData)?;
+        self.write_register(Register::TEMP_OFFSET_ADJUST, code)
+    }
+
+    /// Set the relative humidity offset adjustment (%RH).
+    ///
+    /// The offset is added to every humidity measurement by the device.
+    /// Valid range: [-25.0, 24.8]. The value is rounded to the nearest
+    /// step of ~0.2%RH.
+    ///
+    /// Returns `Error::InvalidInputData` if the value is out of range.
+    pub fn set_humidity_offset(&mut self, offset: f32) -> Result<(), Error<E>> {
+        let code = conversion::humidity_offset_to_code(offset).ok_or(Error::InvalidInputData)?;
+        self.write_register(Register::HUM_OFFSET_ADJUST, code)
+    }
+
+    /// Read the applied temperature offset adjustment (°C)
+    pub fn temperature_offset(&mut self) -> Result<f32, Error<E>> {
+        let code = self.read_register(Register::TEMP_OFFSET_ADJUST)?;
+        Ok(conversion::temperature_offset_from_code(code))
+    }
+
+    /// Read the applied relative humidity offset adjustment (%RH)
+    pub fn humidity_offset(&mut self) -> Result<f32, Error<E>> {
+        let code = self.read_register(Register::HUM_OFFSET_ADJUST)?;
+        Ok(conversion::humidity_offset_from_code(code))
+    }
+
+    /// Set the temperature low threshold (°C).
+    ///
+    /// Valid range: [-40.0, 124.35]. The threshold register has an 8-bit
+    /// resolution so the value is rounded to the nearest step of ~0.64°C.
+    ///
+    /// Returns `Error::InvalidInputData` if the value is out of range.
+    pub fn set_temperature_threshold_low(&mut self, temperature: f32) -> Result<(), Error<E>> {
+        let code = conversion::temperature_to_u8(temperature).ok_or(Error::InvalidInputData)?;
+        self.write_register(Register::TEMP_THR_L, code)
+    }
+
+    /// Set the temperature high threshold (°C).
+    ///
+    /// Valid range: [-40.0, 124.35]. The threshold register has an 8-bit
+    /// resolution so the value is rounded to the nearest step of ~0.64°C.
+    ///
+    /// Returns `Error::InvalidInputData` if the value is out of range.
+    pub fn set_temperature_threshold_high(&mut self, temperature: f32) -> Result<(), Error<E>> {
+        let code = conversion::temperature_to_u8(temperature).ok_or(Error::InvalidInputData)?;
+        self.write_register(Register::TEMP_THR_H, code)
+    }
+
+    /// Set the relative humidity low threshold (%RH).
+    ///
+    /// Valid range: [0.0, 99.6]. The threshold register has an 8-bit
+    /// resolution so the value is rounded to the nearest step of ~0.39%RH.
+    ///
+    /// Returns `Error::InvalidInputData` if the value is out of range.
+    pub fn set_humidity_threshold_low(&mut self, humidity: f32) -> Result<(), Error<E>> {
+        let code = conversion::humidity_to_u8(humidity).ok_or(Error::InvalidInputData)?;
+        self.write_register(Register::RH_THR_L, code)
+    }
+
+    /// Set the relative humidity high threshold (%RH).
+    ///
+    /// Valid range: [0.0, 99.6]. The threshold register has an 8-bit
+    /// resolution so the value is rounded to the nearest step of ~0.39%RH.
+    ///
+    /// Returns `Error::InvalidInputData` if the value is out of range.
+    pub fn set_humidity_threshold_high(&mut self, humidity: f32) -> Result<(), Error<E>> {
+        let code = conversion::humidity_to_u8(humidity).ok_or(Error::InvalidInputData)?;
+        self.write_register(Register::RH_THR_H, code)
+    }
+
+    /// Read the temperature low threshold (°C)
+    pub fn temperature_threshold_low(&mut self) -> Result<f32, Error<E>> {
+        let code = self.read_register(Register::TEMP_THR_L)?;
+        Ok(conversion::temperature_from_u8(code))
+    }
+
+    /// Read the temperature high threshold (°C)
+    pub fn temperature_threshold_high(&mut self) -> Result<f32, Error<E>> {
+        let code = self.read_register(Register::TEMP_THR_H)?;
+        Ok(conversion::temperature_from_u8(code))
+    }
+
+    /// Read the relative humidity low threshold (%RH)
+    pub fn humidity_threshold_low(&mut self) -> Result<f32, Error<E>> {
+        let code = self.read_register(Register::RH_THR_L)?;
+        Ok(conversion::humidity_from_u8(code))
+    }
+
+    /// Read the relative humidity high threshold (%RH)
+    pub fn humidity_threshold_high(&mut self) -> Result<f32, Error<E>> {
+        let code = self.read_register(Register::RH_THR_H)?;
+        Ok(conversion::humidity_from_u8(code))
+    }
+
+    /// Set the interrupt configuration.
+    ///
+    /// This enables the given interrupt sources and configures the
+    /// interrupt pin polarity and mode. The interrupt pin is enabled if
+    /// any source is enabled and set to high impedance otherwise.
+    pub fn set_interrupt_config(&mut self, config: InterruptConfig) -> Result<(), Error<E>> {
+        self.write_register(Register::INTERRUPT_ENABLE, config.enable_bits())?;
+        let dev_config = self.dev_config.with_interrupt_config(config);
+        self.write_register(Register::DEVICE_CONFIG, dev_config.bits)?;
+        self.dev_config = dev_config;
+        Ok(())
+    }
+
+    /// Enable the integrated heater.
+    ///
+    /// The heater can be used to remove condensation from the sensor. While
+    /// it is running, the temperature and humidity measurements are not
+    /// representative of the environment.
+    /// Consider using [`clear_condensation()`](#method.clear_condensation)
+    /// instead, which switches the heater off again after a bounded time.
+    pub fn enable_heater(&mut self) -> Result<(), Error<E>> {
+        let config = self.dev_config.with_high(BitFlags::HEAT_EN);
+        self.write_register(Register::DEVICE_CONFIG, config.bits)?;
+        self.dev_config = config;
+        Ok(())
+    }
+
+    /// Disable the integrated heater.
+    pub fn disable_heater(&mut self) -> Result<(), Error<E>> {
+        let config = self.dev_config.with_low(BitFlags::HEAT_EN);
+        self.write_register(Register::DEVICE_CONFIG, config.bits)?;
+        self.dev_config = config;
+        Ok(())
+    }
+
+    /// Run the integrated heater for the given duration (ms) to clear
+    /// condensation and then restore the previous device configuration.
+    ///
+    /// This blocks for the whole duration.
+    ///
+    /// Returns `Error::InvalidInputData` if the duration is 0 or greater
+    /// than 10000 ms.
+    ///
+    /// If restoring the previous configuration fails, it is tried once more.
+    /// If that fails as well, the error is returned and the heater may still
+    /// be on. Call [`disable_heater()`](#method.disable_heater) in that case.
+    pub fn clear_condensation<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        duration_ms: u16,
+    ) -> Result<(), Error<E>> {
+        if duration_ms == 0 || duration_ms > MAX_HEATER_DURATION_MS {
+            return Err(Error::InvalidInputData);
+        }
+        let previous = self.dev_config;
+        self.enable_heater()?;
+        delay.delay_ms(u32::from(duration_ms));
+        if self
+            .write_register(Register::DEVICE_CONFIG, previous.bits)
+            .is_err()
+        {
+            // Do not leave the heater running after a transient bus error.
+            self.write_register(Register::DEVICE_CONFIG, previous.bits)?;
+        }
+        self.dev_config = previous;
+        Ok(())
+    }
+
+    /// Read the maximum temperature measured since power-on or the last
+    /// software reset (°C).
+    ///
+    /// The device keeps track of the maximum temperature measured. Reading
+    /// this value does not clear it. It is only reset on power-on or with
+    /// [`software_reset()`](#method.software_reset).
+    ///
+    /// Note that the peak register only holds the 8 most significant bits
+    /// of the measurement, so its resolution is ~0.64°C.
+    pub fn max_temperature(&mut self) -> Result<f32, Error<E>> {
+        let code = self.read_register(Register::TEMP_MAX)?;
+        Ok(conversion::temperature_from_u8(code))
+    }
+
+    /// Read the maximum relative humidity measured since power-on or the
+    /// last software reset (%RH).
+    ///
+    /// The device keeps track of the maximum relative humidity measured.
+    /// Reading this value does not clear it. It is only reset on power-on or
+    /// with [`software_reset()`](#method.software_reset).
+    ///
+    /// Note that the peak register only holds the 8 most significant bits
+    /// of the measurement, so its resolution is ~0.39%RH.
+    pub fn max_humidity(&mut self) -> Result<f32, Error<E>> {
+        let code = self.read_register(Register::HUM_MAX)?;
+        Ok(conversion::humidity_from_u8(code))
     }
 
     /// Get device ID
@@ -63,76 +361,437 @@ where
     pub fn manufacturer_id(&mut self) -> Result<u16, Error<E>> {
         self.read_double_register(Register::MANUFACTURER_ID_L)
     }
+
+    /// Read the complete device configuration.
+    ///
+    /// All registers are read in a single transaction. The peak registers
+    /// are only included if the peak readout is enabled with
+    /// [`enable_peak_readout()`](#method.enable_peak_readout), since they
+    /// hold measurement results.
+    pub fn read_configuration(&mut self) -> Result<DeviceConfiguration, Error<E>> {
+        if !self.read_peaks {
+            return self.read_configuration_registers();
+        }
+        let mut data = [0; 11];
+        self.read_data(Register::TEMP_MAX, &mut data)?;
+        let mut registers = [0; 9];
+        registers.copy_from_slice(&data[2..]);
+        Ok(DeviceConfiguration {
+            temperature_max: Some(data[0]),
+            humidity_max: Some(data[1]),
+            ..DeviceConfiguration::from_registers(registers)
+        })
+    }
+
+    /// Read the configuration registers without the peak registers.
+    fn read_configuration_registers(&mut self) -> Result<DeviceConfiguration, Error<E>> {
+        let mut data = [0; 9];
+        self.read_data(Register::INTERRUPT_ENABLE, &mut data)?;
+        Ok(DeviceConfiguration::from_registers(data))
+    }
+
+    /// Read the contents of all registers.
+    ///
+    /// Registers 0x00 to 0x0F are read in a single transaction, followed by
+    /// the identification registers. The result can be printed as a decoded
+    /// view for diagnostics.
+    ///
+    /// Note that reading the registers clears the status register on the
+    /// device. A data ready status pending for a one-shot measurement
+    /// started with [`read()`](#method.read) is lost.
+    pub fn dump_registers(&mut self) -> Result<RegisterDump, Error<E>> {
+        let mut registers = [0; 16];
+        self.read_data(Register::TEMP_L, &mut registers)?;
+        let mut ids = [0; 4];
+        self.read_data(Register::MANUFACTURER_ID_L, &mut ids)?;
+        Ok(RegisterDump {
+            registers,
+            manufacturer_id: u16::from_le_bytes([ids[0], ids[1]]),
+            device_id: u16::from_le_bytes([ids[2], ids[3]]),
+        })
+    }
+
+    /// Apply a complete device configuration.
+    ///
+    /// The current configuration is read back from the device and only the
+    /// registers that differ are written. The peak registers are neither
+    /// read nor written, since they hold measurement results.
+    ///
+    /// The measurement trigger and software reset bits are ignored. The
+    /// auto measurement mode bits are kept as they are, since they are
+    /// determined by the current mode (one-shot or continuous). Use
+    /// [`into_continuous()`](#method.into_continuous) and
+    /// [`into_one_shot()`](#method.into_one_shot) to change it.
+    pub fn apply_configuration(&mut self, config: &DeviceConfiguration) -> Result<(), Error<E>> {
+        let mut target = *config;
+        target.measurement_config &= !BitFlags::MEAS_TRIG;
+        target.device_config = (target.device_config
+            & !(BitFlags::SOFT_RESET | BitFlags::AMM_MASK))
+            | (self.dev_config.bits & BitFlags::AMM_MASK);
+        let mut current = self.read_configuration_registers()?;
+        current.measurement_config &= !BitFlags::MEAS_TRIG;
+        let current = current.to_registers();
+        let target_registers = target.to_registers();
+        let registers = current.iter().zip(target_registers.iter());
+        for (offset, (current, target)) in registers.enumerate() {
+            if current != target {
+                self.write_register(Register::INTERRUPT_ENABLE + offset as u8, *target)?;
+            }
+        }
+        self.dev_config = Config {
+            bits: target.device_config,
+        };
+        self.meas_config = Config {
+            bits: target.measurement_config,
+        };
+        Ok(())
+    }
 }
 
 impl<I2C, E> Hdc20xx<I2C, mode::OneShot>
 where
-    I2C: i2c::WriteRead<Error = E> + i2c::Write<Error = E>,
+    I2C: I2c<Error = E>,
 {
     /// Make measurement of temperature or temperature and humidity according
     /// to the configuration.
     ///
     /// Note that all status except the last one once data becomes available
     /// are discarded.
+    ///
+    /// The expected time until the data is available for the configured
+    /// resolution can be retrieved with
+    /// [`conversion_time_us()`](#method.conversion_time_us).
     pub fn read(&mut self) -> nb::Result<Measurement, Error<E>> {
         if self.was_measurement_started {
             let status = self.status()?;
             if status.data_ready {
-                let include_humidity = !self.meas_config.is_high(BitFlags::TEMP_ONLY);
-                let mut data = [0; 4];
-                if include_humidity {
-                    self.read_data(Register::TEMP_L, &mut data)?;
-                } else {
-                    self.read_data(Register::TEMP_L, &mut data[..2])?;
-                }
+                let measurement = self.read_measurement(status)?;
                 self.was_measurement_started = false;
-                let temp_raw = u16::from(data[0]) | (u16::from(data[1]) << 8);
-                let temp = f32::from(temp_raw) / 65536.0 * 165.0 - 40.0;
-                if include_humidity {
-                    let rh_raw = u16::from(data[2]) | (u16::from(data[3]) << 8);
-                    let rh = f32::from(rh_raw) / 65536.0 * 100.0;
-                    Ok(Measurement {
-                        temperature: temp,
-                        humidity: Some(rh),
-                        status,
-                    })
-                } else {
-                    Ok(Measurement {
-                        temperature: temp,
-                        humidity: None,
-                        status,
-                    })
-                }
+                Ok(measurement)
             } else {
                 Err(nb::Error::WouldBlock)
             }
         } else {
-            let meas_conf = self.meas_config.with_high(BitFlags::MEAS_TRIG);
-            self.write_register(Register::MEAS_CONF, meas_conf.bits)?;
-            self.was_measurement_started = true;
+            self.trigger_measurement()?;
             Err(nb::Error::WouldBlock)
         }
     }
 
+    /// Make measurement of temperature or temperature and humidity according
+    /// to the configuration and return the raw register codes.
+    ///
+    /// This works like [`read()`](#method.read) but no conversion into
+    /// physical units takes place. The peak values are not read.
+    pub fn read_raw(&mut self) -> nb::Result<RawMeasurement, Error<E>> {
+        if self.was_measurement_started {
+            let status = self.status()?;
+            if status.data_ready {
+                let measurement = self.read_raw_measurement(status)?;
+                self.was_measurement_started = false;
+                Ok(measurement)
+            } else {
+                Err(nb::Error::WouldBlock)
+            }
+        } else {
+            self.trigger_measurement()?;
+            Err(nb::Error::WouldBlock)
+        }
+    }
+
+    /// Make measurement of temperature or temperature and humidity according
+    /// to the configuration and wait until it is available.
+    ///
+    /// This triggers a measurement and waits for the expected conversion
+    /// time between status reads. `Error::Timeout` is returned if the data
+    /// is not ready after 10 status reads.
+    pub fn measure<D: DelayNs>(&mut self, delay: &mut D) -> Result<Measurement, Error<E>> {
+        self.trigger_measurement()?;
+        for _ in 0..MAX_DATA_READY_POLLS {
+            delay.delay_us(self.conversion_time_us());
+            let status = self.status()?;
+            if status.data_ready {
+                let measurement = self.read_measurement(status)?;
+                self.was_measurement_started = false;
+                return Ok(measurement);
+            }
+        }
+        self.was_measurement_started = false;
+        Err(Error::Timeout)
+    }
+
+    /// Make measurement of temperature or temperature and humidity according
+    /// to the configuration using the interrupt pin to detect when the data
+    /// is ready.
+    ///
+    /// The data ready interrupt must be enabled with
+    /// [`set_interrupt_config()`](#method.set_interrupt_config) and `pin`
+    /// must be connected to the interrupt pin of the device.
+    ///
+    /// In contrast to [`read()`](#method.read), the status is not polled
+    /// over I²C. Once the pin is active, the data and the status are read
+    /// in a single transaction, which also deasserts the interrupt.
+    ///
+    /// If the status read does not report that data is ready, the pin was
+    /// active due to another interrupt source or an earlier measurement
+    /// and `nb::Error::WouldBlock` is returned.
+    pub fn read_with_drdy_pin<P: InputPin>(
+        &mut self,
+        pin: &mut P,
+    ) -> nb::Result<Measurement, Error<E>> {
+        if self.was_measurement_started {
+            let is_active = if self.dev_config.is_high(BitFlags::INT_POL) {
+                pin.is_high()
+            } else {
+                pin.is_low()
+            };
+            if !is_active.map_err(|e| Error::Pin(e.kind()))? {
+                return Err(nb::Error::WouldBlock);
+            }
+            let measurement = self.read_measurement_and_status()?;
+            if !measurement.status.data_ready {
+                return Err(nb::Error::WouldBlock);
+            }
+            self.was_measurement_started = false;
+            Ok(measurement)
+        } else {
+            self.trigger_measurement()?;
+            Err(nb::Error::WouldBlock)
+        }
+    }
+
+    pub(crate) fn trigger_measurement(&mut self) -> Result<(), Error<E>> {
+        let meas_conf = self.meas_config.with_high(BitFlags::MEAS_TRIG);
+        self.write_register(Register::MEAS_CONF, meas_conf.bits)?;
+        self.was_measurement_started = true;
+        Ok(())
+    }
+
+    /// Change into continuous (auto measurement) mode.
+    ///
+    /// The device will then measure autonomously at the given sampling rate.
+    ///
+    /// In case of error, the unchanged device is returned as well. If the
+    /// measurement cannot be triggered after the sampling rate was written,
+    /// the previous sampling rate configuration is written back.
+    pub fn into_continuous(
+        mut self,
+        rate: SamplingRate,
+    ) -> Result<Hdc20xx<I2C, mode::Continuous>, ModeChangeError<E, Self>> {
+        let config = self
+            .dev_config
+            .with_low(BitFlags::AMM_MASK)
+            .with_high(rate.bits());
+        if let Err(e) = self.write_register_raw(Register::DEVICE_CONFIG, config.bits) {
+            return Err(ModeChangeError::I2C(e, self));
+        }
+        let meas_conf = self.meas_config.with_high(BitFlags::MEAS_TRIG);
+        if let Err(e) = self.write_register_raw(Register::MEAS_CONF, meas_conf.bits) {
+            // Otherwise the next one-shot trigger would start auto measurements.
+            // The trigger error is more relevant than a possible error here.
+            let _ = self.write_register_raw(Register::DEVICE_CONFIG, self.dev_config.bits);
+            return Err(ModeChangeError::I2C(e, self));
+        }
+        self.dev_config = config;
+        Ok(self.with_mode())
+    }
+
     /// Software reset
-    pub fn software_reset(&mut self) -> Result<(), Error<E>> {
-        let conf = self.meas_config.with_high(BitFlags::SOFT_RESET);
-        self.write_register(Register::MEAS_CONF, conf.bits)
+    ///
+    /// This resets all the registers to their power-on values, waits for
+    /// the reset to complete and resets the configuration cached in the
+    /// driver accordingly.
+    ///
+    /// The reset bit is read after waiting 0.5 ms each time. `Error::Timeout`
+    /// is returned if the reset has not completed after 5 ms.
+    ///
+    /// This is only available in one-shot mode. A device in continuous mode
+    /// must be changed with [`into_one_shot()`](#method.into_one_shot) first.
+    pub fn software_reset<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<E>> {
+        self.write_register(Register::DEVICE_CONFIG, BitFlags::SOFT_RESET)?;
+        let mut polls = 0;
+        loop {
+            delay.delay_us(RESET_POLL_INTERVAL_US);
+            if (self.read_register(Register::DEVICE_CONFIG)? & BitFlags::SOFT_RESET) == 0 {
+                break;
+            }
+            polls += 1;
+            if polls >= MAX_RESET_POLLS {
+                return Err(Error::Timeout);
+            }
+        }
+        self.meas_config = Config::default();
+        self.dev_config = Config::default();
+        self.was_measurement_started = false;
+        Ok(())
+    }
+
+    /// Load the configuration present in the device into the driver.
+    ///
+    /// This is useful to continue with a device that was configured earlier,
+    /// for example by another program or before a restart. Nothing is
+    /// written to the device. The peak registers are not read.
+    ///
+    /// Since the driver is in one-shot mode, the auto measurement mode bits
+    /// are not taken over. If the device is running in auto measurement
+    /// mode, it keeps running until the next write to the device
+    /// configuration, which stops it. Use
+    /// [`into_continuous()`](#method.into_continuous) to continue with auto
+    /// measurements.
+    ///
+    /// Returns the configuration as read from the device.
+    pub fn load_configuration(&mut self) -> Result<DeviceConfiguration, Error<E>> {
+        let config = self.read_configuration_registers()?;
+        self.dev_config = Config {
+            bits: config.device_config & !(BitFlags::SOFT_RESET | BitFlags::AMM_MASK),
+        };
+        self.meas_config = Config {
+            bits: config.measurement_config & !BitFlags::MEAS_TRIG,
+        };
+        Ok(config)
+    }
+}
+
+impl<I2C, E> Hdc20xx<I2C, mode::Continuous>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Read the last measurement of temperature or temperature and humidity
+    /// according to the configuration.
+    ///
+    /// This does not trigger a new measurement. The returned status reports
+    /// whether new data has become available since the last status read.
+    pub fn read(&mut self) -> Result<Measurement, Error<E>> {
+        let status = self.status()?;
+        self.read_measurement(status)
+    }
+
+    /// Read the raw register codes of the last measurement of temperature
+    /// or temperature and humidity according to the configuration.
+    ///
+    /// This works like [`read()`](#method.read-1) but no conversion into
+    /// physical units takes place. The peak values are not read.
+    pub fn read_raw(&mut self) -> Result<RawMeasurement, Error<E>> {
+        let status = self.status()?;
+        self.read_raw_measurement(status)
+    }
+
+    /// Change into one-shot measurement mode.
+    ///
+    /// This stops the autonomous measurements.
+    ///
+    /// In case of error, the unchanged device is returned as well.
+    pub fn into_one_shot(
+        mut self,
+    ) -> Result<Hdc20xx<I2C, mode::OneShot>, ModeChangeError<E, Self>> {
+        let config = self.dev_config.with_low(BitFlags::AMM_MASK);
+        if let Err(e) = self.write_register_raw(Register::DEVICE_CONFIG, config.bits) {
+            return Err(ModeChangeError::I2C(e, self));
+        }
+        self.dev_config = config;
+        Ok(self.with_mode())
+    }
+}
+
+impl<I2C, E, MODE> Hdc20xx<I2C, MODE>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Read the data registers and the status (and the peak registers if
+    /// enabled) in a single transaction.
+    fn read_measurement_and_status(&mut self) -> Result<Measurement, Error<E>> {
+        let mut data = [0; 7];
+        let len = if self.read_peaks { 7 } else { 5 };
+        self.read_data(Register::TEMP_L, &mut data[..len])?;
+        let status = conversion::status_from_register(data[4]);
+        let include_humidity = self.meas_config.includes_humidity();
+        let values = if include_humidity {
+            &data[..4]
+        } else {
+            &data[..2]
+        };
+        let mut measurement =
+            Measurement::from(conversion::raw_measurement_from_data(values, status));
+        if self.read_peaks {
+            measurement.max_temperature = Some(conversion::temperature_from_u8(data[5]));
+            if include_humidity {
+                measurement.max_humidity = Some(conversion::humidity_from_u8(data[6]));
+            }
+        }
+        Ok(measurement)
+    }
+
+    fn read_raw_measurement(&mut self, status: Status) -> Result<RawMeasurement, Error<E>> {
+        let mut data = [0; 4];
+        let data = if self.meas_config.includes_humidity() {
+            &mut data[..]
+        } else {
+            &mut data[..2]
+        };
+        self.read_data(Register::TEMP_L, data)?;
+        Ok(conversion::raw_measurement_from_data(data, status))
+    }
+
+    fn read_measurement(&mut self, status: Status) -> Result<Measurement, Error<E>> {
+        let raw = self.read_raw_measurement(status)?;
+        let mut measurement = Measurement::from(raw);
+        if self.read_peaks {
+            let mut peaks = [0; 2];
+            if raw.humidity.is_some() {
+                self.read_data(Register::TEMP_MAX, &mut peaks)?;
+                measurement.max_humidity = Some(conversion::humidity_from_u8(peaks[1]));
+            } else {
+                self.read_data(Register::TEMP_MAX, &mut peaks[..1])?;
+            }
+            measurement.max_temperature = Some(conversion::temperature_from_u8(peaks[0]));
+        }
+        Ok(measurement)
     }
 }
 
 impl Config {
-    fn with_high(self, mask: u8) -> Self {
+    pub(crate) fn with_high(self, mask: u8) -> Self {
         Config {
             bits: self.bits | mask,
         }
     }
-    fn with_low(self, mask: u8) -> Self {
+    pub(crate) fn with_low(self, mask: u8) -> Self {
         Config {
             bits: self.bits & !mask,
         }
     }
-    fn is_high(self, mask: u8) -> bool {
+    pub(crate) fn is_high(self, mask: u8) -> bool {
         (self.bits & mask) != 0
     }
+
+    /// `DEVICE_CONFIG` with the interrupt pin configured
+    pub(crate) fn with_interrupt_config(self, config: InterruptConfig) -> Self {
+        let dev_config = if config.enable_bits() != 0 {
+            self.with_high(BitFlags::INT_EN)
+        } else {
+            self.with_low(BitFlags::INT_EN)
+        };
+        let dev_config = match config.polarity {
+            InterruptPolarity::ActiveLow => dev_config.with_low(BitFlags::INT_POL),
+            InterruptPolarity::ActiveHigh => dev_config.with_high(BitFlags::INT_POL),
+        };
+        match config.mode {
+            InterruptMode::LevelSensitive => dev_config.with_low(BitFlags::INT_MODE),
+            InterruptMode::Comparator => dev_config.with_high(BitFlags::INT_MODE),
+        }
+    }
+
+    /// Whether a measurement with this `MEAS_CONF` includes humidity
+    pub(crate) fn includes_humidity(self) -> bool {
+        !self.is_high(BitFlags::TEMP_ONLY)
+    }
+
+    /// Typical conversion time (µs) of a measurement with this `MEAS_CONF`
+    pub(crate) fn conversion_time_us(self) -> u32 {
+        let temp_time = Resolution::from_bits(self.bits >> 6).temperature_conversion_time_us();
+        if self.includes_humidity() {
+            temp_time + Resolution::from_bits(self.bits >> 4).humidity_conversion_time_us()
+        } else {
+            temp_time
+        }
+    }
 }
@@ -0,0 +1,118 @@
+use core::fmt::Debug;
+use embedded_hal::i2c::{self, ErrorKind, ErrorType, I2c, Operation, SevenBitAddress};
+use embedded_hal_0_2::blocking::i2c as i2c_0_2;
+
+/// Adapter to use an I²C bus implementing the `embedded-hal` 0.2 traits
+/// with this driver.
+///
+/// Like the driver for `embedded-hal` 0.2, the bus only needs to implement
+/// the `Write` and `WriteRead` traits.
+///
+/// Only available with the `eh02` feature.
+///
+/// ```
+/// use embedded_hal_mock::eh0::i2c::Mock as I2cMock;
+/// use hdc20xx::{Hdc20xx, I2cCompat, SlaveAddr};
+///
+/// # let i2c_0_2 = I2cMock::new(&[]);
+/// let sensor = Hdc20xx::new(I2cCompat::new(i2c_0_2), SlaveAddr::default());
+/// let mut i2c_0_2 = sensor.destroy().into_inner();
+/// # i2c_0_2.done();
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct I2cCompat<I2C> {
+    i2c: I2C,
+}
+
+impl<I2C> I2cCompat<I2C> {
+    /// Wrap an `embedded-hal` 0.2 I²C bus.
+    pub fn new(i2c: I2C) -> Self {
+        I2cCompat { i2c }
+    }
+
+    /// Return the wrapped I²C bus.
+    pub fn into_inner(self) -> I2C {
+        self.i2c
+    }
+}
+
+/// Error of an `embedded-hal` 0.2 I²C bus wrapped in [`I2cCompat`].
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum I2cCompatError<E> {
+    /// Error of the wrapped bus
+    Bus(E),
+    /// A read operation that does not follow a write operation was requested.
+    ///
+    /// This is not supported since the wrapped bus only needs to implement
+    /// the `Write` and `WriteRead` traits. The driver does not issue such
+    /// operations.
+    UnsupportedRead,
+}
+
+impl<E: Debug> i2c::Error for I2cCompatError<E> {
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Other
+    }
+}
+
+impl<I2C, E> ErrorType for I2cCompat<I2C>
+where
+    I2C: i2c_0_2::Write<Error = E>,
+    E: Debug,
+{
+    type Error = I2cCompatError<E>;
+}
+
+impl<I2C, E> I2c<SevenBitAddress> for I2cCompat<I2C>
+where
+    I2C: i2c_0_2::Write<Error = E> + i2c_0_2::WriteRead<Error = E>,
+    E: Debug,
+{
+    /// Returns `I2cCompatError::UnsupportedRead`.
+    fn read(&mut self, _address: u8, _read: &mut [u8]) -> Result<(), Self::Error> {
+        Err(I2cCompatError::UnsupportedRead)
+    }
+
+    fn write(&mut self, address: u8, write: &[u8]) -> Result<(), Self::Error> {
+        self.i2c.write(address, write).map_err(I2cCompatError::Bus)
+    }
+
+    fn write_read(
+        &mut self,
+        address: u8,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.i2c
+            .write_read(address, write, read)
+            .map_err(I2cCompatError::Bus)
+    }
+
+    /// Operations are executed one after the other as there is no
+    /// transactional I²C trait in `embedded-hal` 0.2. A write operation
+    /// followed by a read operation is executed as a single write-read.
+    /// Other read operations return `I2cCompatError::UnsupportedRead`.
+    fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        let mut operations = operations.iter_mut().peekable();
+        while let Some(operation) = operations.next() {
+            match operation {
+                Operation::Write(write) => {
+                    if let Some(Operation::Read(read)) = operations.peek_mut() {
+                        self.write_read(address, write, read)?;
+                        operations.next();
+                    } else {
+                        self.write(address, write)?;
+                    }
+                }
+                Operation::Read(_) => return Err(I2cCompatError::UnsupportedRead),
+            }
+        }
+        Ok(())
+    }
+}
@@ -4,18 +4,97 @@
 //!
 //! [`embedded-hal`]: https://github.com/rust-embedded/embedded-hal
 //!
+//! This driver is built on the `embedded-hal` 1.0 traits. Buses implementing
+//! only the `embedded-hal` 0.2 traits can be used with the `I2cCompat`
+//! adapter available with the `eh02` feature.
+//!
+//! An asynchronous driver built on the `embedded-hal-async` traits,
+//! `AsyncHdc20xx`, is available with the `async` feature.
+//!
+//! The measurements can be obtained as typed `Celsius` and `RelativeHumidity`
+//! values. With the `uom` feature, they are additionally available as
+//! [`uom`] quantities.
+//!
+//! With the `defmt` feature, all public types implement `defmt::Format` and
+//! the driver emits trace-level log messages for register writes and data
+//! ready status reads.
+//!
+//! With the `serde` feature, the measurement, status and configuration types
+//! implement `serde::Serialize` and `serde::Deserialize`.
+//!
+//! Several devices can share an I²C bus with the bus sharing implementations
+//! of [`embedded-hal-bus`] like `RefCellDevice`, `CriticalSectionDevice` or
+//! `MutexDevice`.
+//!
+//! Many devices behind I²C multiplexers like the TCA9548A can be measured
+//! together with [`SensorArray`].
+//!
+//! With the `sim` feature, the `sim` module provides a software simulation
+//! of the device implementing the I²C and delay traits so that code using the
+//! driver can be tested without hardware.
+//!
+//! With the `cli` feature, the `hdc20xx` command-line tool for devices
+//! connected to a Linux `i2c-dev` bus is built.
+//!
 //! This driver allows you to:
 //! - Set the measurement mode. Temperature only or temperature and humidity. See: [`set_measurement_mode()`].
+//! - Set the temperature and humidity resolution. See: [`set_temperature_resolution()`].
+//! - Get the expected conversion time. See: [`conversion_time_us()`].
 //! - Make one shot measurement. See: [`read()`].
+//! - Read the raw temperature and humidity register codes. See: [`read_raw()`].
+//! - Convert the raw codes into milli-degrees Celsius and milli-percent without floating-point arithmetic. See: [`temperature_milli_celsius()`].
+//! - Calculate the dew point, frost point, absolute humidity, mixing ratio and heat index. See: [`dew_point()`] and the [`psychrometrics`] module.
+//! - Get the temperature and humidity as typed units. See: [`temperature_celsius()`].
+//! - Make one shot measurement waiting with a delay until it is available. See: [`measure()`].
+//! - Change into continuous (auto measurement) mode and back. See: [`into_continuous()`].
+//! - Make one shot measurement using the interrupt pin to detect when the data is ready. See: [`read_with_drdy_pin()`].
+//! - Read the last measurement in continuous mode. See: [`read()`](struct.Hdc20xx.html#method.read-1).
 //! - Read the data and interrupt status. See: [`status()`].
+//! - Read the maximum temperature and humidity peak values. See: [`max_temperature()`].
+//! - Include the peak values in the measurements. See: [`enable_peak_readout()`].
+//! - Set the temperature and humidity offset adjustment. See: [`set_temperature_offset()`].
+//! - Set the temperature and humidity interrupt thresholds. See: [`set_temperature_threshold_high()`].
+//! - Configure the interrupt sources and the interrupt pin. See: [`set_interrupt_config()`].
+//! - Enable/disable the integrated heater. See: [`enable_heater()`].
+//! - Run the heater for a limited time to clear condensation. See: [`clear_condensation()`].
+//! - Read the complete device configuration. See: [`read_configuration()`].
+//! - Restore a complete device configuration writing only the registers that differ. See: [`apply_configuration()`].
+//! - Continue with the configuration present in the device without writing to it. See: [`load_configuration()`].
+//! - Read all registers and print a decoded diagnostic view. See: [`dump_registers()`].
 //! - Trigger a software reset. See: [`software_reset()`].
+//! - Create the device verifying the manufacturer and device ID. See: [`new_checked()`].
 //! - Read the manufacturer ID. See: [`manufacturer_id()`].
 //! - Read the device ID. See: [`device_id()`].
 //!
 //! [`set_measurement_mode()`]: struct.Hdc20xx.html#method.set_measurement_mode
+//! [`set_temperature_resolution()`]: struct.Hdc20xx.html#method.set_temperature_resolution
+//! [`conversion_time_us()`]: struct.Hdc20xx.html#method.conversion_time_us
 //! [`read()`]: struct.Hdc20xx.html#method.read
+//! [`measure()`]: struct.Hdc20xx.html#method.measure
+//! [`read_raw()`]: struct.Hdc20xx.html#method.read_raw
+//! [`temperature_milli_celsius()`]: struct.RawMeasurement.html#method.temperature_milli_celsius
+//! [`dew_point()`]: struct.Measurement.html#method.dew_point
+//! [`temperature_celsius()`]: struct.Measurement.html#method.temperature_celsius
+//! [`uom`]: https://crates.io/crates/uom
+//! [`embedded-hal-bus`]: https://crates.io/crates/embedded-hal-bus
+//! [`SensorArray`]: struct.SensorArray.html
+//! [`psychrometrics`]: psychrometrics/index.html
+//! [`read_with_drdy_pin()`]: struct.Hdc20xx.html#method.read_with_drdy_pin
+//! [`into_continuous()`]: struct.Hdc20xx.html#method.into_continuous
 //! [`status()`]: struct.Hdc20xx.html#method.status
+//! [`max_temperature()`]: struct.Hdc20xx.html#method.max_temperature
+//! [`enable_peak_readout()`]: struct.Hdc20xx.html#method.enable_peak_readout
+//! [`set_temperature_offset()`]: struct.Hdc20xx.html#method.set_temperature_offset
+//! [`set_temperature_threshold_high()`]: struct.Hdc20xx.html#method.set_temperature_threshold_high
+//! [`set_interrupt_config()`]: struct.Hdc20xx.html#method.set_interrupt_config
+//! [`enable_heater()`]: struct.Hdc20xx.html#method.enable_heater
+//! [`clear_condensation()`]: struct.Hdc20xx.html#method.clear_condensation
+//! [`read_configuration()`]: struct.Hdc20xx.html#method.read_configuration
+//! [`apply_configuration()`]: struct.Hdc20xx.html#method.apply_configuration
+//! [`load_configuration()`]: struct.Hdc20xx.html#method.load_configuration
+//! [`dump_registers()`]: struct.Hdc20xx.html#method.dump_registers
 //! [`software_reset()`]: struct.Hdc20xx.html#method.software_reset
+//! [`new_checked()`]: struct.Hdc20xx.html#method.new_checked
 //! [`manufacturer_id()`]: struct.Hdc20xx.html#method.manufacturer_id
 //! [`device_id()`]: struct.Hdc20xx.html#method.device_id
 //!
@@ -52,7 +131,7 @@
 //! ### Make a one-shot temperature and humidity measurement
 //!
 //! ```no_run
-//! use embedded_hal::blocking::delay::DelayMs;
+//! use embedded_hal::delay::DelayNs;
 //! use hdc20xx::{Hdc20xx, SlaveAddr};
 //! use linux_embedded_hal::{Delay, I2cdev};
 //!
@@ -61,25 +140,117 @@
 //! let address = SlaveAddr::default();
 //! let mut sensor = Hdc20xx::new(dev, address);
 //! loop {
-//!     loop {
-//!         let result = sensor.read();
-//!         match result {
-//!             Err(nb::Error::WouldBlock) => delay.delay_ms(100_u8),
-//!             Err(e) => {
-//!                 println!("Error! {:?}", e);
-//!             }
-//!             Ok(data) => {
-//!                 println!(
-//!                     "Temperature: {:2}°C, Humidity: {:2}%",
-//!                     data.temperature,
-//!                     data.humidity.unwrap()
-//!                 );
-//!             }
+//!     let result = sensor.read();
+//!     match result {
+//!         Err(nb::Error::WouldBlock) => delay.delay_us(sensor.conversion_time_us()),
+//!         Err(e) => {
+//!             println!("Error! {:?}", e);
+//!         }
+//!         Ok(data) => {
+//!             println!(
+//!                 "Temperature: {:2}°C, Humidity: {:2}%",
+//!                 data.temperature,
+//!                 data.humidity.unwrap()
+//!             );
 //!         }
 //!     }
 //! }
 //! ```
 //!
+//! ### Make a one-shot measurement waiting with a delay
+//!
+//! ```no_run
+//! use hdc20xx::{Hdc20xx, SlaveAddr};
+//! use linux_embedded_hal::{Delay, I2cdev};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let address = SlaveAddr::default();
+//! let mut sensor = Hdc20xx::new(dev, address);
+//! let data = sensor.measure(&mut Delay {}).unwrap();
+//! println!(
+//!     "Temperature: {:2}°C, Humidity: {:2}%",
+//!     data.temperature,
+//!     data.humidity.unwrap()
+//! );
+//! ```
+//!
+//! ### Calculate the dew point and absolute humidity
+//!
+//! ```no_run
+//! use hdc20xx::{Hdc20xx, SlaveAddr};
+//! use linux_embedded_hal::{Delay, I2cdev};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut sensor = Hdc20xx::new(dev, SlaveAddr::default());
+//! let data = sensor.measure(&mut Delay {}).unwrap();
+//! println!(
+//!     "Dew point: {:2}°C, Absolute humidity: {:2}g/m³",
+//!     data.dew_point().unwrap(),
+//!     data.absolute_humidity().unwrap()
+//! );
+//! ```
+//!
+//! ### Make a one-shot measurement using the interrupt pin
+//!
+//! ```no_run
+//! use hdc20xx::{Hdc20xx, InterruptConfig, InterruptPolarity, SlaveAddr};
+//! use linux_embedded_hal::{CdevPin, I2cdev};
+//! use linux_embedded_hal::gpio_cdev::{Chip, LineRequestFlags};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut chip = Chip::new("/dev/gpiochip0").unwrap();
+//! let line = chip.get_line(17).unwrap();
+//! let handle = line.request(LineRequestFlags::INPUT, 0, "hdc20xx").unwrap();
+//! let mut int_pin = CdevPin::new(handle).unwrap();
+//! let address = SlaveAddr::default();
+//! let mut sensor = Hdc20xx::new(dev, address);
+//! sensor
+//!     .set_interrupt_config(InterruptConfig {
+//!         data_ready: true,
+//!         polarity: InterruptPolarity::ActiveHigh,
+//!         ..Default::default()
+//!     })
+//!     .unwrap();
+//! let data = nb::block!(sensor.read_with_drdy_pin(&mut int_pin)).unwrap();
+//! println!("Temperature: {:2}°C", data.temperature);
+//! ```
+//!
+//! ### Measure continuously at 1 Hz
+//!
+//! ```no_run
+//! use hdc20xx::{Hdc20xx, SamplingRate, SlaveAddr};
+//! use linux_embedded_hal::I2cdev;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let address = SlaveAddr::default();
+//! let sensor = Hdc20xx::new(dev, address);
+//! let mut sensor = sensor.into_continuous(SamplingRate::Hz1).ok().unwrap();
+//! let data = sensor.read().unwrap();
+//! println!("Temperature: {:2}°C", data.temperature);
+//! // Go back to one-shot mode
+//! let sensor = sensor.into_one_shot().ok().unwrap();
+//! ```
+//!
+//! ### Use two devices on the same bus
+//!
+//! ```no_run
+//! use core::cell::RefCell;
+//! use embedded_hal_bus::i2c::RefCellDevice;
+//! use hdc20xx::{Hdc20xx, SlaveAddr};
+//! use linux_embedded_hal::{Delay, I2cdev};
+//!
+//! let bus = RefCell::new(I2cdev::new("/dev/i2c-1").unwrap());
+//! let mut indoor = Hdc20xx::new(RefCellDevice::new(&bus), SlaveAddr::default());
+//! let mut outdoor = Hdc20xx::new(RefCellDevice::new(&bus), SlaveAddr::Alternative(true));
+//! let mut delay = Delay {};
+//! let indoor_data = indoor.measure(&mut delay).unwrap();
+//! let outdoor_data = outdoor.measure(&mut delay).unwrap();
+//! println!(
+//!     "Indoor: {:2}°C, Outdoor: {:2}°C",
+//!     indoor_data.temperature, outdoor_data.temperature
+//! );
+//! ```
+//!
 //! ### Use an alternative address
 //!
 //! ```no_run
@@ -103,6 +274,20 @@
 //! sensor.set_measurement_mode(MeasurementMode::TemperatureOnly).unwrap();
 //! ```
 //!
+//! ### Configure 11-bit temperature and 9-bit humidity resolution
+//!
+//! ```no_run
+//! use hdc20xx::{Hdc20xx, Resolution, SlaveAddr};
+//! use linux_embedded_hal::I2cdev;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let address = SlaveAddr::default();
+//! let mut sensor = Hdc20xx::new(dev, address);
+//! sensor.set_temperature_resolution(Resolution::Bits11).unwrap();
+//! sensor.set_humidity_resolution(Resolution::Bits9).unwrap();
+//! println!("Conversion time: {} us", sensor.conversion_time_us());
+//! ```
+//!
 //! ### Read the manufacturer and device ID
 //!
 //! ```no_run
@@ -120,6 +305,126 @@
 //! );
 //! ```
 //!
+//! ### Calibrate the temperature and humidity offsets
+//!
+//! ```no_run
+//! use hdc20xx::{Hdc20xx, SlaveAddr};
+//! use linux_embedded_hal::I2cdev;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let address = SlaveAddr::default();
+//! let mut sensor = Hdc20xx::new(dev, address);
+//! sensor.set_temperature_offset(-1.5).unwrap();
+//! sensor.set_humidity_offset(2.0).unwrap();
+//! let offset = sensor.temperature_offset().unwrap();
+//! println!("Applied temperature offset: {}°C", offset);
+//! ```
+//!
+//! ### Set the temperature and humidity thresholds
+//!
+//! ```no_run
+//! use hdc20xx::{Hdc20xx, SlaveAddr};
+//! use linux_embedded_hal::I2cdev;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let address = SlaveAddr::default();
+//! let mut sensor = Hdc20xx::new(dev, address);
+//! sensor.set_temperature_threshold_low(-5.0).unwrap();
+//! sensor.set_temperature_threshold_high(35.0).unwrap();
+//! sensor.set_humidity_threshold_low(20.0).unwrap();
+//! sensor.set_humidity_threshold_high(80.0).unwrap();
+//! let status = sensor.status().unwrap();
+//! if status.high_temp_threshold_exceeded {
+//!     println!("Too hot!");
+//! }
+//! ```
+//!
+//! ### Configure the interrupt pin for data ready and high temperature
+//!
+//! ```no_run
+//! use hdc20xx::{Hdc20xx, InterruptConfig, InterruptPolarity, SlaveAddr};
+//! use linux_embedded_hal::I2cdev;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let address = SlaveAddr::default();
+//! let mut sensor = Hdc20xx::new(dev, address);
+//! sensor.set_temperature_threshold_high(35.0).unwrap();
+//! sensor
+//!     .set_interrupt_config(InterruptConfig {
+//!         data_ready: true,
+//!         high_temperature: true,
+//!         polarity: InterruptPolarity::ActiveHigh,
+//!         ..Default::default()
+//!     })
+//!     .unwrap();
+//! ```
+//!
+//! ### Run the heater for 2 seconds to clear condensation
+//!
+//! ```no_run
+//! use hdc20xx::{Hdc20xx, SlaveAddr};
+//! use linux_embedded_hal::{Delay, I2cdev};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let address = SlaveAddr::default();
+//! let mut sensor = Hdc20xx::new(dev, address);
+//! sensor.clear_condensation(&mut Delay {}, 2000).unwrap();
+//! ```
+//!
+//! ### Read the temperature and humidity peak values
+//!
+//! ```no_run
+//! use hdc20xx::{Hdc20xx, SamplingRate, SlaveAddr};
+//! use linux_embedded_hal::I2cdev;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let address = SlaveAddr::default();
+//! let sensor = Hdc20xx::new(dev, address);
+//! let mut sensor = sensor.into_continuous(SamplingRate::OnePer60s).ok().unwrap();
+//! // ... some time later
+//! let max_temp = sensor.max_temperature().unwrap();
+//! let max_rh = sensor.max_humidity().unwrap();
+//! println!("Max. temperature: {}°C, max. humidity: {}%", max_temp, max_rh);
+//!
+//! // Alternatively, include them in every measurement
+//! sensor.enable_peak_readout();
+//! let data = sensor.read().unwrap();
+//! println!("Max. temperature: {:?}°C", data.max_temperature);
+//! ```
+//!
+//! ### Create the device verifying its identity
+//!
+//! ```no_run
+//! use hdc20xx::{Error, Hdc20xx, SlaveAddr, Variant};
+//! use linux_embedded_hal::I2cdev;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let address = SlaveAddr::default();
+//! match Hdc20xx::new_checked(dev, address, Variant::Hdc2080) {
+//!     Ok(sensor) => println!("Found {}", sensor.variant().unwrap()),
+//!     Err(Error::UnexpectedDeviceId {
+//!         manufacturer,
+//!         device,
+//!     }) => println!("Unknown device: {:#06x} {:#06x}", manufacturer, device),
+//!     Err(e) => println!("Error: {:?}", e),
+//! }
+//! ```
+//!
+//! ### Verify and restore the configuration
+//!
+//! ```no_run
+//! use hdc20xx::{Hdc20xx, SlaveAddr};
+//! use linux_embedded_hal::I2cdev;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut sensor = Hdc20xx::new(dev, SlaveAddr::default());
+//! let profile = sensor.read_configuration().unwrap();
+//! // ...
+//! if sensor.read_configuration().unwrap() != profile {
+//!     sensor.apply_configuration(&profile).unwrap();
+//! }
+//! ```
+//!
 //! ### Read the data and interrupt status
 //!
 //! ```no_run
@@ -137,23 +442,70 @@
 #![no_std]
 
 use core::marker::PhantomData;
+
+/// Log a message at trace level if the `defmt` feature is enabled.
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "defmt")]
+        defmt::trace!($($arg)*);
+    };
+}
+
+mod conversion;
 mod device_impl;
+pub mod psychrometrics;
 mod types;
-pub use crate::types::{Error, Measurement, MeasurementMode, SlaveAddr, Status};
+pub mod units;
+pub use crate::types::{
+    DeviceConfiguration, Error, InterruptConfig, InterruptMode, InterruptPolarity, Measurement,
+    MeasurementMode, ModeChangeError, RawMeasurement, RegisterDump, Resolution, SamplingRate,
+    SlaveAddr, Status, Variant,
+};
+pub use crate::units::{Celsius, RelativeHumidity};
+mod array;
+pub use crate::array::{ArrayMeasurement, ChannelSelector, SensorArray, SensorArrayError};
+mod tca9548a;
+pub use crate::tca9548a::{Tca9548a, Tca9548aError};
+#[cfg(feature = "async")]
+mod asynch;
+#[cfg(feature = "async")]
+pub use crate::asynch::AsyncHdc20xx;
+#[cfg(feature = "eh02")]
+mod eh02;
 mod register_address;
-use crate::register_address::{BitFlags, Register, BASE_ADDR};
+#[cfg(feature = "sim")]
+pub mod sim;
+#[cfg(feature = "eh02")]
+pub use crate::eh02::{I2cCompat, I2cCompatError};
+use crate::register_address::{BitFlags, Register, BASE_ADDR, DEVICE_ID, MANUFACTURER_ID};
+
+/// Number of times the data ready status is polled before timing out
+const MAX_DATA_READY_POLLS: u8 = 10;
+
+/// Number of times the software reset bit is polled before timing out
+const MAX_RESET_POLLS: u8 = 10;
+
+/// Time to wait before each poll of the software reset bit (µs)
+///
+/// Together with `MAX_RESET_POLLS` this covers the start-up time of 3 ms.
+const RESET_POLL_INTERVAL_US: u32 = 500;
 
 /// HDC2080, HDC2021 and HDC2010 device driver
 #[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Hdc20xx<I2C, MODE> {
     i2c: I2C,
     address: u8,
     meas_config: Config,
+    dev_config: Config,
     was_measurement_started: bool,
+    read_peaks: bool,
+    variant: Option<Variant>,
     _mode: PhantomData<MODE>,
 }
 
 #[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 struct Config {
     bits: u8,
 }
@@ -161,8 +513,12 @@ struct Config {
 /// Mode marker
 pub mod mode {
     /// One shot measurement mode
+    #[derive(Debug)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
     pub struct OneShot(());
     /// Continuous measurement mode
+    #[derive(Debug)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
     pub struct Continuous(());
 }
 
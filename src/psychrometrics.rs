@@ -0,0 +1,214 @@
+//! Derived psychrometric quantities.
+//!
+//! These functions compute quantities derived from a temperature (°C) and a
+//! relative humidity (%RH) reading. They only rely on [`libm`] so they are
+//! usable in `no_std` environments.
+//!
+//! The same quantities are available as methods on [`Measurement`], which
+//! return `None` if the humidity was not measured.
+//!
+//! The saturation vapor pressure is computed with the Magnus formula using
+//! the coefficients from Sonntag (1990), which are valid from -45°C to 60°C
+//! over water and from -65°C to 0°C over ice.
+//!
+//! [`libm`]: https://crates.io/crates/libm
+
+use crate::Measurement;
+use libm::{expf, fabsf, logf, sqrtf};
+
+/// Standard atmospheric pressure at sea level (hPa)
+pub const STANDARD_PRESSURE_HPA: f32 = 1013.25;
+
+/// Magnus formula coefficients over water
+const WATER_A: f32 = 17.62;
+const WATER_B: f32 = 243.12;
+/// Magnus formula coefficients over ice
+const ICE_A: f32 = 22.46;
+const ICE_B: f32 = 272.62;
+/// Saturation vapor pressure at 0°C (hPa)
+const E0_HPA: f32 = 6.112;
+
+/// Saturation vapor pressure over water (hPa)
+pub fn saturation_vapor_pressure(temperature: f32) -> f32 {
+    E0_HPA * expf(WATER_A * temperature / (WATER_B + temperature))
+}
+
+/// Actual vapor pressure (hPa)
+pub fn vapor_pressure(temperature: f32, humidity: f32) -> f32 {
+    saturation_vapor_pressure(temperature) * humidity / 100.0
+}
+
+/// Dew point (°C) using the Magnus formula.
+///
+/// Returns `None` if the relative humidity is not positive.
+pub fn dew_point(temperature: f32, humidity: f32) -> Option<f32> {
+    let gamma = magnus_gamma(temperature, humidity)?;
+    Some(WATER_B * gamma / (WATER_A - gamma))
+}
+
+/// Frost point (°C) using the Magnus formula over ice.
+///
+/// This is the temperature at which the water vapor would deposit as ice.
+/// Below 0°C it is slightly higher than the dew point.
+///
+/// Returns `None` if the relative humidity is not positive.
+pub fn frost_point(temperature: f32, humidity: f32) -> Option<f32> {
+    let gamma = magnus_gamma(temperature, humidity)?;
+    Some(ICE_B * gamma / (ICE_A - gamma))
+}
+
+/// Absolute humidity (g/m³)
+pub fn absolute_humidity(temperature: f32, humidity: f32) -> f32 {
+    // Ideal gas law with the specific gas constant of water vapor
+    // (461.5 J/(kg·K)) and the vapor pressure in hPa.
+    216.7 * vapor_pressure(temperature, humidity) / (273.15 + temperature)
+}
+
+/// Mixing ratio (g of water vapor per kg of dry air) at the given
+/// atmospheric pressure (hPa).
+///
+/// Use [`STANDARD_PRESSURE_HPA`] if the pressure is not known.
+pub fn mixing_ratio(temperature: f32, humidity: f32, pressure_hpa: f32) -> f32 {
+    let e = vapor_pressure(temperature, humidity);
+    622.0 * e / (pressure_hpa - e)
+}
+
+/// Heat index (°C) as defined by the US National Weather Service.
+///
+/// This uses the Rothfusz regression including its adjustments and falls
+/// back to the simpler Steadman formula for heat index values below 80°F
+/// (26.7°C), where the regression is not valid. The result is only
+/// meaningful for warm conditions.
+pub fn heat_index(temperature: f32, humidity: f32) -> f32 {
+    let t = temperature * 1.8 + 32.0;
+    let rh = humidity;
+    let simple = 0.5 * (t + 61.0 + (t - 68.0) * 1.2 + rh * 0.094);
+    let hi = if (simple + t) / 2.0 < 80.0 {
+        simple
+    } else {
+        let mut hi = -42.379 + 2.049_015_2 * t + 10.143_331 * rh
+            - 0.224_755_4 * t * rh
+            - 0.006_837_83 * t * t
+            - 0.054_817_17 * rh * rh
+            + 0.001_228_74 * t * t * rh
+            + 0.000_852_82 * t * rh * rh
+            - 0.000_001_99 * t * t * rh * rh;
+        if rh < 13.0 && (80.0..=112.0).contains(&t) {
+            hi -= (13.0 - rh) / 4.0 * sqrtf((17.0 - fabsf(t - 95.0)) / 17.0);
+        } else if rh > 85.0 && (80.0..=87.0).contains(&t) {
+            hi += (rh - 85.0) / 10.0 * (87.0 - t) / 5.0;
+        }
+        hi
+    };
+    (hi - 32.0) / 1.8
+}
+
+fn magnus_gamma(temperature: f32, humidity: f32) -> Option<f32> {
+    if humidity > 0.0 {
+        Some(logf(humidity / 100.0) + WATER_A * temperature / (WATER_B + temperature))
+    } else {
+        None
+    }
+}
+
+impl Measurement {
+    /// Dew point (°C)
+    ///
+    /// Returns `None` if the humidity was not measured or is zero.
+    /// See [`psychrometrics::dew_point()`](psychrometrics/fn.dew_point.html).
+    pub fn dew_point(&self) -> Option<f32> {
+        dew_point(self.temperature, self.humidity?)
+    }
+
+    /// Frost point (°C)
+    ///
+    /// Returns `None` if the humidity was not measured or is zero.
+    /// See [`psychrometrics::frost_point()`](psychrometrics/fn.frost_point.html).
+    pub fn frost_point(&self) -> Option<f32> {
+        frost_point(self.temperature, self.humidity?)
+    }
+
+    /// Absolute humidity (g/m³)
+    ///
+    /// Returns `None` if the humidity was not measured.
+    pub fn absolute_humidity(&self) -> Option<f32> {
+        Some(absolute_humidity(self.temperature, self.humidity?))
+    }
+
+    /// Mixing ratio (g/kg) at the given atmospheric pressure (hPa)
+    ///
+    /// Use [`STANDARD_PRESSURE_HPA`](psychrometrics/constant.STANDARD_PRESSURE_HPA.html)
+    /// if the pressure is not known.
+    ///
+    /// Returns `None` if the humidity was not measured.
+    pub fn mixing_ratio(&self, pressure_hpa: f32) -> Option<f32> {
+        Some(mixing_ratio(self.temperature, self.humidity?, pressure_hpa))
+    }
+
+    /// Heat index (°C)
+    ///
+    /// Returns `None` if the humidity was not measured.
+    /// See [`psychrometrics::heat_index()`](psychrometrics/fn.heat_index.html).
+    pub fn heat_index(&self) -> Option<f32> {
+        Some(heat_index(self.temperature, self.humidity?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(expected: f32, actual: f32, epsilon: f32) {
+        assert!(
+            (expected - actual).abs() <= epsilon,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn dew_point_equals_temperature_at_saturation() {
+        assert_near(20.0, dew_point(20.0, 100.0).unwrap(), 0.001);
+        assert_near(-10.0, dew_point(-10.0, 100.0).unwrap(), 0.001);
+    }
+
+    #[test]
+    fn can_calculate_dew_point() {
+        assert_near(9.26, dew_point(20.0, 50.0).unwrap(), 0.05);
+        assert_near(21.3, dew_point(30.0, 60.0).unwrap(), 0.1);
+    }
+
+    #[test]
+    fn frost_point_is_above_dew_point_below_freezing() {
+        let dew = dew_point(-5.0, 80.0).unwrap();
+        let frost = frost_point(-5.0, 80.0).unwrap();
+        assert!(frost > dew);
+        assert!(frost < -5.0);
+    }
+
+    #[test]
+    fn rejects_zero_humidity() {
+        assert_eq!(None, dew_point(20.0, 0.0));
+        assert_eq!(None, frost_point(20.0, 0.0));
+    }
+
+    #[test]
+    fn can_calculate_absolute_humidity() {
+        assert_near(17.3, absolute_humidity(20.0, 100.0), 0.1);
+        assert_near(8.6, absolute_humidity(20.0, 50.0), 0.1);
+    }
+
+    #[test]
+    fn can_calculate_mixing_ratio() {
+        assert_near(7.3, mixing_ratio(20.0, 50.0, STANDARD_PRESSURE_HPA), 0.1);
+    }
+
+    #[test]
+    fn can_calculate_heat_index() {
+        // NWS heat index table: 90°F at 70% RH is 106°F
+        assert_near(41.1, heat_index(32.22, 70.0), 0.5);
+        // Below 80°F the heat index is close to the temperature
+        assert_near(20.0, heat_index(20.0, 50.0), 1.0);
+    }
+}
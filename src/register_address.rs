@@ -1,12 +1,24 @@
 use crate::{Error, Hdc20xx};
-use embedded_hal::blocking::i2c;
+use embedded_hal::i2c::I2c;
 
 pub const BASE_ADDR: u8 = 0x40;
+pub const MANUFACTURER_ID: u16 = 0x5449;
+pub const DEVICE_ID: u16 = 0x07D0;
 
 pub struct Register;
 impl Register {
     pub const TEMP_L: u8 = 0x00;
     pub const DRDY: u8 = 0x04;
+    pub const TEMP_MAX: u8 = 0x05;
+    pub const HUM_MAX: u8 = 0x06;
+    pub const INTERRUPT_ENABLE: u8 = 0x07;
+    pub const TEMP_OFFSET_ADJUST: u8 = 0x08;
+    pub const HUM_OFFSET_ADJUST: u8 = 0x09;
+    pub const TEMP_THR_L: u8 = 0x0A;
+    pub const TEMP_THR_H: u8 = 0x0B;
+    pub const RH_THR_L: u8 = 0x0C;
+    pub const RH_THR_H: u8 = 0x0D;
+    pub const DEVICE_CONFIG: u8 = 0x0E;
     pub const MEAS_CONF: u8 = 0x0F;
     pub const MANUFACTURER_ID_L: u8 = 0xFC;
     pub const DEVICE_ID_L: u8 = 0xFE;
@@ -15,6 +27,13 @@ impl Register {
 pub struct BitFlags;
 impl BitFlags {
     pub const SOFT_RESET: u8 = 1 << 7;
+    pub const AMM_MASK: u8 = 0b0111_0000;
+    pub const HEAT_EN: u8 = 1 << 3;
+    pub const INT_EN: u8 = 1 << 2;
+    pub const INT_POL: u8 = 1 << 1;
+    pub const INT_MODE: u8 = 1;
+    pub const TRES_MASK: u8 = 0b1100_0000;
+    pub const HRES_MASK: u8 = 0b0011_0000;
     pub const TEMP_ONLY: u8 = 1 << 1;
     pub const MEAS_TRIG: u8 = 1;
     pub const DRDY_STATUS: u8 = 1 << 7;
@@ -22,23 +41,28 @@ impl BitFlags {
     pub const TL_STATUS: u8 = 1 << 5;
     pub const HH_STATUS: u8 = 1 << 4;
     pub const HL_STATUS: u8 = 1 << 3;
+    pub const DRDY_ENABLE: u8 = 1 << 7;
+    pub const TH_ENABLE: u8 = 1 << 6;
+    pub const TL_ENABLE: u8 = 1 << 5;
+    pub const HH_ENABLE: u8 = 1 << 4;
+    pub const HL_ENABLE: u8 = 1 << 3;
 }
 
 impl<I2C, E, MODE> Hdc20xx<I2C, MODE>
 where
-    I2C: i2c::Write<Error = E>,
+    I2C: I2c<Error = E>,
 {
     pub(crate) fn write_register(&mut self, register: u8, data: u8) -> Result<(), Error<E>> {
+        self.write_register_raw(register, data).map_err(Error::I2C)
+    }
+
+    pub(crate) fn write_register_raw(&mut self, register: u8, data: u8) -> Result<(), E> {
+        trace!("write register {=u8:#04x}: {=u8:#04x}", register, data);
         let payload: [u8; 2] = [register, data];
         let addr = self.address;
-        self.i2c.write(addr, &payload).map_err(Error::I2C)
+        self.i2c.write(addr, &payload)
     }
-}
 
-impl<I2C, E, MODE> Hdc20xx<I2C, MODE>
-where
-    I2C: i2c::WriteRead<Error = E>,
-{
     pub(crate) fn read_double_register(&mut self, register: u8) -> Result<u16, Error<E>> {
         let mut data = [0, 0];
         self.read_data(register, &mut data)
@@ -0,0 +1,463 @@
+//! Software simulation of the device.
+//!
+//! The [`Simulator`] models the register map of the HDC20xx devices
+//! including one-shot and auto measurement mode, the data ready timing,
+//! the resolution, the offset adjustment, the thresholds, the peak registers
+//! and the software reset. Time only passes through a virtual clock, which
+//! is advanced explicitly or by using the simulated delay.
+//!
+//! This allows testing the behavior of code using the driver without
+//! hardware and without listing the expected I²C transactions.
+//!
+//! ```
+//! use hdc20xx::{sim::Simulator, Hdc20xx, SlaveAddr};
+//!
+//! let sim = Simulator::new(SlaveAddr::default());
+//! sim.set_temperature(21.5);
+//! sim.set_humidity(40.0);
+//! let mut sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+//! let data = sensor.measure(&mut sim.delay()).unwrap();
+//! assert!((data.temperature - 21.5).abs() < 0.01);
+//! assert!((data.humidity.unwrap() - 40.0).abs() < 0.01);
+//! ```
+
+use crate::{
+    BitFlags, Config, DeviceConfiguration, Register, SlaveAddr, DEVICE_ID, MANUFACTURER_ID,
+};
+use core::cell::RefCell;
+use embedded_hal::{
+    delay::DelayNs,
+    digital::{self, InputPin},
+    i2c::{self, ErrorKind, NoAcknowledgeSource, Operation},
+};
+
+/// Number of simulated registers before the identification registers
+const REGISTER_COUNT: usize = 0x10;
+
+/// Simulated device
+///
+/// The simulator is shared between the simulated bus, delay and interrupt
+/// pin, which borrow it.
+#[derive(Debug)]
+pub struct Simulator {
+    state: RefCell<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    address: u8,
+    registers: [u8; REGISTER_COUNT],
+    pointer: u8,
+    temperature: f32,
+    humidity: f32,
+    now_ns: u64,
+    conversion_done_ns: Option<u64>,
+    next_auto_ns: Option<u64>,
+}
+
+impl Simulator {
+    /// Create a new simulated device at the given address.
+    ///
+    /// The device is in its power-on reset state, the temperature is 25°C
+    /// and the relative humidity is 50%.
+    pub fn new(address: SlaveAddr) -> Self {
+        let mut state = State {
+            address: address.addr(),
+            registers: [0; REGISTER_COUNT],
+            pointer: 0,
+            temperature: 25.0,
+            humidity: 50.0,
+            now_ns: 0,
+            conversion_done_ns: None,
+            next_auto_ns: None,
+        };
+        state.reset();
+        Simulator {
+            state: RefCell::new(state),
+        }
+    }
+
+    /// Simulated I²C bus connected to the device
+    pub fn i2c(&self) -> SimI2c<'_> {
+        SimI2c { sim: self }
+    }
+
+    /// Simulated delay advancing the virtual clock
+    pub fn delay(&self) -> SimDelay<'_> {
+        SimDelay { sim: self }
+    }
+
+    /// Simulated interrupt pin of the device
+    ///
+    /// If the interrupt pin is disabled, it is in high impedance and reads
+    /// high as if it had a pull-up resistor.
+    pub fn interrupt_pin(&self) -> SimInterruptPin<'_> {
+        SimInterruptPin { sim: self }
+    }
+
+    /// Set the ambient temperature (°C) measured from now on.
+    pub fn set_temperature(&self, temperature: f32) {
+        self.state.borrow_mut().temperature = temperature;
+    }
+
+    /// Set the ambient relative humidity (%RH) measured from now on.
+    pub fn set_humidity(&self, humidity: f32) {
+        self.state.borrow_mut().humidity = humidity;
+    }
+
+    /// Advance the virtual clock by the given number of microseconds.
+    ///
+    /// Pending conversions complete and auto measurements are started as
+    /// their time is reached.
+    pub fn advance_us(&self, us: u32) {
+        self.advance_ns(u64::from(us) * 1_000);
+    }
+
+    /// Advance the virtual clock by the given number of milliseconds.
+    pub fn advance_ms(&self, ms: u32) {
+        self.advance_ns(u64::from(ms) * 1_000_000);
+    }
+
+    /// Elapsed virtual time (µs)
+    pub fn now_us(&self) -> u64 {
+        self.state.borrow().now_ns / 1_000
+    }
+
+    /// Register contents without the side effects of reading them over I²C
+    pub fn register(&self, register: u8) -> u8 {
+        self.state.borrow().peek(register)
+    }
+
+    /// Whether a conversion is in progress
+    pub fn is_converting(&self) -> bool {
+        self.state.borrow().conversion_done_ns.is_some()
+    }
+
+    /// Level of the interrupt pin
+    ///
+    /// `None` if the interrupt pin is disabled (high impedance).
+    pub fn interrupt_pin_level(&self) -> Option<bool> {
+        self.state.borrow().interrupt_pin_level()
+    }
+
+    fn advance_ns(&self, ns: u64) {
+        let mut state = self.state.borrow_mut();
+        let target = state.now_ns + ns;
+        state.advance_to(target);
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Simulator {
+    fn format(&self, f: defmt::Formatter) {
+        let state = self.state.borrow();
+        defmt::write!(
+            f,
+            "Simulator {{ address: {=u8:#x}, now_ns: {=u64}, registers: {=[u8]:#x} }}",
+            state.address,
+            state.now_ns,
+            state.registers
+        )
+    }
+}
+
+impl State {
+    fn reset(&mut self) {
+        self.registers = [0; REGISTER_COUNT];
+        let config = DeviceConfiguration::default().to_registers();
+        let start = usize::from(Register::INTERRUPT_ENABLE);
+        self.registers[start..].copy_from_slice(&config);
+        self.conversion_done_ns = None;
+        self.next_auto_ns = None;
+    }
+
+    fn peek(&self, register: u8) -> u8 {
+        match register {
+            r if usize::from(r) < REGISTER_COUNT => self.registers[usize::from(r)],
+            r if r == Register::MANUFACTURER_ID_L => MANUFACTURER_ID as u8,
+            r if r == Register::MANUFACTURER_ID_L + 1 => (MANUFACTURER_ID >> 8) as u8,
+            r if r == Register::DEVICE_ID_L => DEVICE_ID as u8,
+            r if r == Register::DEVICE_ID_L + 1 => (DEVICE_ID >> 8) as u8,
+            _ => 0,
+        }
+    }
+
+    fn read(&mut self, register: u8) -> u8 {
+        let value = self.peek(register);
+        if register == Register::DRDY {
+            // In comparator mode the threshold status follows the last
+            // conversion instead of being cleared.
+            let kept = if self.get(Register::DEVICE_CONFIG) & BitFlags::INT_MODE != 0 {
+                value & !BitFlags::DRDY_STATUS
+            } else {
+                0
+            };
+            self.set(Register::DRDY, kept);
+        }
+        value
+    }
+
+    fn write(&mut self, register: u8, value: u8) {
+        match register {
+            r if r == Register::DEVICE_CONFIG => self.write_device_config(value),
+            r if r == Register::MEAS_CONF => self.write_measurement_config(value),
+            r if (Register::INTERRUPT_ENABLE..Register::DEVICE_CONFIG).contains(&r) => {
+                self.set(r, value)
+            }
+            // Data, status, peak and identification registers are read-only.
+            _ => (),
+        }
+    }
+
+    fn write_device_config(&mut self, value: u8) {
+        if value & BitFlags::SOFT_RESET != 0 {
+            self.reset();
+            return;
+        }
+        self.set(Register::DEVICE_CONFIG, value);
+        if value & BitFlags::AMM_MASK == 0 {
+            self.next_auto_ns = None;
+        }
+    }
+
+    fn write_measurement_config(&mut self, value: u8) {
+        self.set(Register::MEAS_CONF, value);
+        if value & BitFlags::MEAS_TRIG != 0 {
+            self.start_conversion();
+            if let Some(period) = self.auto_period_ns() {
+                self.next_auto_ns = Some(self.now_ns + period);
+            }
+        }
+    }
+
+    fn auto_period_ns(&self) -> Option<u64> {
+        let ms: u64 = match (self.get(Register::DEVICE_CONFIG) & BitFlags::AMM_MASK) >> 4 {
+            1 => 120_000,
+            2 => 60_000,
+            3 => 10_000,
+            4 => 5_000,
+            5 => 1_000,
+            6 => 500,
+            7 => 200,
+            _ => return None,
+        };
+        Some(ms * 1_000_000)
+    }
+
+    fn start_conversion(&mut self) {
+        let config = Config {
+            bits: self.get(Register::MEAS_CONF),
+        };
+        let duration = u64::from(config.conversion_time_us()) * 1_000;
+        self.conversion_done_ns = Some(self.now_ns + duration);
+    }
+
+    fn complete_conversion(&mut self) {
+        let config = self.get(Register::MEAS_CONF);
+        let temp_offset = i32::from(self.get(Register::TEMP_OFFSET_ADJUST) as i8) * 64;
+        let temp = quantize(
+            raw_from_value(self.temperature + 40.0, 165.0) + temp_offset,
+            config >> 6,
+        );
+        self.set_data(Register::TEMP_L, temp);
+        let temp_msb = (temp >> 8) as u8;
+        self.set_max(Register::TEMP_MAX, temp_msb);
+        let mut status = BitFlags::DRDY_STATUS;
+        if temp_msb > self.get(Register::TEMP_THR_H) {
+            status |= BitFlags::TH_STATUS;
+        }
+        if temp_msb < self.get(Register::TEMP_THR_L) {
+            status |= BitFlags::TL_STATUS;
+        }
+        if config & BitFlags::TEMP_ONLY == 0 {
+            let hum_offset = i32::from(self.get(Register::HUM_OFFSET_ADJUST) as i8) * 128;
+            let hum = quantize(
+                raw_from_value(self.humidity, 100.0) + hum_offset,
+                config >> 4,
+            );
+            self.set_data(Register::TEMP_L + 2, hum);
+            let hum_msb = (hum >> 8) as u8;
+            self.set_max(Register::HUM_MAX, hum_msb);
+            if hum_msb > self.get(Register::RH_THR_H) {
+                status |= BitFlags::HH_STATUS;
+            }
+            if hum_msb < self.get(Register::RH_THR_L) {
+                status |= BitFlags::HL_STATUS;
+            }
+        }
+        if self.get(Register::DEVICE_CONFIG) & BitFlags::INT_MODE == 0 {
+            status |= self.get(Register::DRDY);
+        }
+        self.set(Register::DRDY, status);
+        self.set(Register::MEAS_CONF, config & !BitFlags::MEAS_TRIG);
+    }
+
+    fn advance_to(&mut self, target_ns: u64) {
+        loop {
+            match (self.conversion_done_ns, self.next_auto_ns) {
+                (Some(done), auto) if done <= target_ns && !matches!(auto, Some(a) if a < done) => {
+                    self.now_ns = done;
+                    self.conversion_done_ns = None;
+                    self.complete_conversion();
+                }
+                (_, Some(auto)) if auto <= target_ns => {
+                    self.now_ns = auto;
+                    self.start_conversion();
+                    self.next_auto_ns = self.auto_period_ns().map(|period| auto + period);
+                }
+                _ => break,
+            }
+        }
+        self.now_ns = target_ns;
+    }
+
+    fn interrupt_pin_level(&self) -> Option<bool> {
+        let device_config = self.get(Register::DEVICE_CONFIG);
+        if device_config & BitFlags::INT_EN == 0 {
+            return None;
+        }
+        let active = self.get(Register::DRDY) & self.get(Register::INTERRUPT_ENABLE) != 0;
+        let active_high = device_config & BitFlags::INT_POL != 0;
+        Some(active == active_high)
+    }
+
+    fn get(&self, register: u8) -> u8 {
+        self.registers[usize::from(register)]
+    }
+
+    fn set(&mut self, register: u8, value: u8) {
+        self.registers[usize::from(register)] = value;
+    }
+
+    fn set_data(&mut self, register: u8, value: u16) {
+        self.set(register, value as u8);
+        self.set(register + 1, (value >> 8) as u8);
+    }
+
+    fn set_max(&mut self, register: u8, value: u8) {
+        if value > self.get(register) {
+            self.set(register, value);
+        }
+    }
+}
+
+/// Raw 16-bit code for a value in the range `0..full_scale`
+fn raw_from_value(value: f32, full_scale: f32) -> i32 {
+    let code = value / full_scale * 65536.0 + 0.5;
+    if code <= 0.0 {
+        0
+    } else {
+        code as i32
+    }
+}
+
+/// Clamp a raw code and keep only the bits of the given resolution
+fn quantize(code: i32, resolution_bits: u8) -> u16 {
+    let mask = match resolution_bits & 0b11 {
+        0 => 0xFFFC,
+        1 => 0xFFE0,
+        _ => 0xFF80,
+    };
+    (code.clamp(0, 0xFFFF) as u16) & mask
+}
+
+/// Simulated I²C bus
+///
+/// Accesses to other addresses than the one of the simulated device are
+/// not acknowledged.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SimI2c<'a> {
+    sim: &'a Simulator,
+}
+
+impl i2c::ErrorType for SimI2c<'_> {
+    type Error = ErrorKind;
+}
+
+impl i2c::I2c for SimI2c<'_> {
+    fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        let mut state = self.sim.state.borrow_mut();
+        if address != state.address {
+            return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+        }
+        for operation in operations {
+            match operation {
+                Operation::Write(data) => {
+                    if let Some((register, values)) = data.split_first() {
+                        state.pointer = *register;
+                        for value in values {
+                            let register = state.pointer;
+                            state.write(register, *value);
+                            state.pointer = register.wrapping_add(1);
+                        }
+                    }
+                }
+                Operation::Read(buffer) => {
+                    for value in buffer.iter_mut() {
+                        let register = state.pointer;
+                        *value = state.read(register);
+                        state.pointer = register.wrapping_add(1);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::i2c::I2c for SimI2c<'_> {
+    async fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        i2c::I2c::transaction(self, address, operations)
+    }
+}
+
+/// Simulated delay advancing the virtual clock of the simulator
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SimDelay<'a> {
+    sim: &'a Simulator,
+}
+
+impl DelayNs for SimDelay<'_> {
+    fn delay_ns(&mut self, ns: u32) {
+        self.sim.advance_ns(u64::from(ns));
+    }
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::delay::DelayNs for SimDelay<'_> {
+    async fn delay_ns(&mut self, ns: u32) {
+        self.sim.advance_ns(u64::from(ns));
+    }
+}
+
+/// Simulated interrupt pin
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SimInterruptPin<'a> {
+    sim: &'a Simulator,
+}
+
+impl digital::ErrorType for SimInterruptPin<'_> {
+    type Error = core::convert::Infallible;
+}
+
+impl InputPin for SimInterruptPin<'_> {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.sim.interrupt_pin_level().unwrap_or(true))
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        self.is_high().map(|high| !high)
+    }
+}
@@ -0,0 +1,71 @@
+use crate::ChannelSelector;
+use embedded_hal::i2c::I2c;
+
+/// Number of channels of the TCA9548A
+const CHANNEL_COUNT: u8 = 8;
+
+/// Errors of the [`Tca9548a`](struct.Tca9548a.html) multiplexer driver
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Tca9548aError<E> {
+    /// I²C communication error
+    I2C(E),
+    /// The channel number is not in the range 0-7
+    InvalidChannel,
+}
+
+/// TCA9548A I²C multiplexer
+///
+/// Only a single channel is connected at a time.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Tca9548a<I2C> {
+    i2c: I2C,
+    address: u8,
+}
+
+impl<I2C> Tca9548a<I2C> {
+    /// Create new instance of the multiplexer.
+    ///
+    /// The address is 0x70 to 0x77 depending on the A0-A2 pins.
+    pub fn new(i2c: I2C, address: u8) -> Self {
+        Tca9548a { i2c, address }
+    }
+
+    /// Destroy driver instance, return I²C bus instance.
+    pub fn destroy(self) -> I2C {
+        self.i2c
+    }
+}
+
+impl<I2C, E> Tca9548a<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Disconnect all channels.
+    pub fn disable_all(&mut self) -> Result<(), Tca9548aError<E>> {
+        self.i2c
+            .write(self.address, &[0])
+            .map_err(Tca9548aError::I2C)
+    }
+}
+
+impl<I2C, E> ChannelSelector for Tca9548a<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    type Error = Tca9548aError<E>;
+
+    /// Connect the given channel (0-7) to the bus.
+    ///
+    /// Returns `Tca9548aError::InvalidChannel` for other channel numbers.
+    fn select(&mut self, channel: u8) -> Result<(), Self::Error> {
+        if channel >= CHANNEL_COUNT {
+            return Err(Tca9548aError::InvalidChannel);
+        }
+        trace!("select channel {=u8}", channel);
+        self.i2c
+            .write(self.address, &[1 << channel])
+            .map_err(Tca9548aError::I2C)
+    }
+}
@@ -1,16 +1,45 @@
-use crate::BASE_ADDR;
+use crate::{conversion, BitFlags, Register, BASE_ADDR};
+use core::fmt;
 
 /// All possible errors in this crate
 #[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Error<E> {
     /// I²C communication error
     I2C(E),
     /// Invalid input data provided
     InvalidInputData,
+    /// The data was not ready or the software reset did not complete in
+    /// the expected time
+    Timeout,
+    /// Error reading the data ready pin
+    Pin(embedded_hal::digital::ErrorKind),
+    /// Unexpected manufacturer or device ID read from the device
+    UnexpectedDeviceId {
+        /// Manufacturer ID read
+        manufacturer: u16,
+        /// Device ID read
+        device: u16,
+    },
+}
+
+/// Error type for mode changes.
+///
+/// This allows to retrieve the unchanged device in case of an error.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ModeChangeError<E, DEV> {
+    /// I²C bus error while changing mode.
+    ///
+    /// `E` is the error that happened.
+    /// `DEV` is the device with the mode unchanged.
+    I2C(E, DEV),
 }
 
 /// Measurement result
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Measurement {
     /// Temperature (°C)
     pub temperature: f32,
@@ -18,12 +47,75 @@ pub struct Measurement {
     ///
     /// Optionally read depending on the measurement configuration
     pub humidity: Option<f32>,
+    /// Maximum temperature since power-on or the last software reset (°C)
+    ///
+    /// Only read if the peak readout is enabled. See
+    /// [`enable_peak_readout()`](struct.Hdc20xx.html#method.enable_peak_readout).
+    pub max_temperature: Option<f32>,
+    /// Maximum relative humidity since power-on or the last software reset (%RH)
+    ///
+    /// Only read if the peak readout is enabled and humidity is measured.
+    pub max_humidity: Option<f32>,
     /// Last status
     pub status: Status,
 }
 
+/// Raw measurement result
+///
+/// Contains the 16-bit codes as read from the data registers. These can be
+/// converted into a [`Measurement`] with `Measurement::from()` or into
+/// fixed-point values without floating-point arithmetic with
+/// [`temperature_milli_celsius()`](#method.temperature_milli_celsius) and
+/// [`humidity_milli_percent()`](#method.humidity_milli_percent).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RawMeasurement {
+    /// Temperature code
+    pub temperature: u16,
+    /// Relative humidity code
+    ///
+    /// Optionally read depending on the measurement configuration
+    pub humidity: Option<u16>,
+    /// Last status
+    pub status: Status,
+}
+
+impl RawMeasurement {
+    /// Temperature in milli-degrees Celsius (m°C)
+    ///
+    /// This is computed using integer arithmetic only, which avoids pulling
+    /// in floating-point code on targets without an FPU.
+    pub fn temperature_milli_celsius(&self) -> i32 {
+        conversion::temperature_milli_from_raw(self.temperature)
+    }
+
+    /// Relative humidity in milli-percent (m%RH), also known as
+    /// per-cent-mille (pcm)
+    ///
+    /// This is computed using integer arithmetic only, which avoids pulling
+    /// in floating-point code on targets without an FPU.
+    pub fn humidity_milli_percent(&self) -> Option<u32> {
+        self.humidity.map(conversion::humidity_milli_from_raw)
+    }
+}
+
+impl From<RawMeasurement> for Measurement {
+    fn from(raw: RawMeasurement) -> Self {
+        Measurement {
+            temperature: conversion::temperature_from_raw(raw.temperature),
+            humidity: raw.humidity.map(conversion::humidity_from_raw),
+            max_temperature: None,
+            max_humidity: None,
+            status: raw.status,
+        }
+    }
+}
+
 /// Status
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Status {
     /// Whether data is ready
     pub data_ready: bool,
@@ -38,22 +130,514 @@ pub struct Status {
 }
 
 /// Measurement mode
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MeasurementMode {
     /// Temperature and humidity (default)
+    #[default]
     TemperatureAndHumidity,
     /// Temperature only
     TemperatureOnly,
 }
 
-impl Default for MeasurementMode {
+/// Measurement resolution
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Resolution {
+    /// 14 bits (default)
+    #[default]
+    Bits14,
+    /// 11 bits
+    Bits11,
+    /// 9 bits
+    Bits9,
+}
+
+impl Resolution {
+    pub(crate) fn bits(self) -> u8 {
+        match self {
+            Resolution::Bits14 => 0,
+            Resolution::Bits11 => 1,
+            Resolution::Bits9 => 2,
+        }
+    }
+
+    pub(crate) fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            0 => Resolution::Bits14,
+            1 => Resolution::Bits11,
+            _ => Resolution::Bits9,
+        }
+    }
+
+    /// Number of bits
+    pub(crate) fn bit_count(self) -> u8 {
+        match self {
+            Resolution::Bits14 => 14,
+            Resolution::Bits11 => 11,
+            Resolution::Bits9 => 9,
+        }
+    }
+
+    /// Typical temperature conversion time (µs)
+    pub(crate) fn temperature_conversion_time_us(self) -> u32 {
+        match self {
+            Resolution::Bits14 => 610,
+            Resolution::Bits11 => 350,
+            Resolution::Bits9 => 225,
+        }
+    }
+
+    /// Typical humidity conversion time (µs)
+    pub(crate) fn humidity_conversion_time_us(self) -> u32 {
+        match self {
+            Resolution::Bits14 => 660,
+            Resolution::Bits11 => 400,
+            Resolution::Bits9 => 275,
+        }
+    }
+}
+
+/// Sampling rate in continuous (auto measurement) mode
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SamplingRate {
+    /// One measurement every 120 seconds (1/120 Hz)
+    OnePer120s,
+    /// One measurement every 60 seconds (1/60 Hz)
+    OnePer60s,
+    /// One measurement every 10 seconds (0.1 Hz)
+    OnePer10s,
+    /// One measurement every 5 seconds (0.2 Hz)
+    OnePer5s,
+    /// 1 Hz
+    Hz1,
+    /// 2 Hz
+    Hz2,
+    /// 5 Hz
+    Hz5,
+}
+
+impl SamplingRate {
+    pub(crate) fn bits(self) -> u8 {
+        let amm = match self {
+            SamplingRate::OnePer120s => 1,
+            SamplingRate::OnePer60s => 2,
+            SamplingRate::OnePer10s => 3,
+            SamplingRate::OnePer5s => 4,
+            SamplingRate::Hz1 => 5,
+            SamplingRate::Hz2 => 6,
+            SamplingRate::Hz5 => 7,
+        };
+        amm << 4
+    }
+}
+
+/// Interrupt pin polarity
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InterruptPolarity {
+    /// Active low (default)
+    #[default]
+    ActiveLow,
+    /// Active high
+    ActiveHigh,
+}
+
+/// Interrupt pin mode
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InterruptMode {
+    /// Level sensitive (default)
+    ///
+    /// The interrupt is asserted when the condition is detected and
+    /// deasserted when the status register is read.
+    #[default]
+    LevelSensitive,
+    /// Comparator mode
+    ///
+    /// The interrupt stays asserted while the condition persists.
+    Comparator,
+}
+
+/// Interrupt configuration
+///
+/// The interrupt pin is enabled if any of the interrupt sources is enabled.
+/// Otherwise it is kept in high impedance.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InterruptConfig {
+    /// Data ready interrupt enabled
+    pub data_ready: bool,
+    /// Temperature high threshold interrupt enabled
+    pub high_temperature: bool,
+    /// Temperature low threshold interrupt enabled
+    pub low_temperature: bool,
+    /// Humidity high threshold interrupt enabled
+    pub high_humidity: bool,
+    /// Humidity low threshold interrupt enabled
+    pub low_humidity: bool,
+    /// Interrupt pin polarity
+    pub polarity: InterruptPolarity,
+    /// Interrupt pin mode
+    pub mode: InterruptMode,
+}
+
+/// Device variant
+///
+/// All variants report the same manufacturer and device ID so the variant
+/// cannot be detected from the device itself.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Variant {
+    /// HDC2010
+    Hdc2010,
+    /// HDC2021
+    Hdc2021,
+    /// HDC2080
+    Hdc2080,
+}
+
+impl Variant {
+    /// Part name
+    pub fn name(self) -> &'static str {
+        match self {
+            Variant::Hdc2010 => "HDC2010",
+            Variant::Hdc2021 => "HDC2021",
+            Variant::Hdc2080 => "HDC2080",
+        }
+    }
+}
+
+impl core::fmt::Display for Variant {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl InterruptConfig {
+    /// `INTERRUPT_ENABLE` register value
+    pub(crate) fn enable_bits(self) -> u8 {
+        let sources = [
+            (self.data_ready, BitFlags::DRDY_ENABLE),
+            (self.high_temperature, BitFlags::TH_ENABLE),
+            (self.low_temperature, BitFlags::TL_ENABLE),
+            (self.high_humidity, BitFlags::HH_ENABLE),
+            (self.low_humidity, BitFlags::HL_ENABLE),
+        ];
+        sources
+            .iter()
+            .filter(|(enabled, _)| *enabled)
+            .fold(0, |bits, (_, mask)| bits | mask)
+    }
+}
+
+/// Complete device configuration
+///
+/// Contains the raw contents of the registers from `TEMPERATURE_MAX` (0x05)
+/// to `MEASUREMENT_CONFIG` (0x0F). The peak registers (0x05 and 0x06) hold
+/// measurement results rather than configuration, so they are only read if
+/// the peak readout is enabled with
+/// [`enable_peak_readout()`](struct.Hdc20xx.html#method.enable_peak_readout)
+/// and are `None` otherwise.
+///
+/// The default value corresponds to the power-on reset state.
+///
+/// See [`read_configuration()`](struct.Hdc20xx.html#method.read_configuration)
+/// and [`apply_configuration()`](struct.Hdc20xx.html#method.apply_configuration).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceConfiguration {
+    /// `TEMPERATURE_MAX` register (0x05), if read
+    pub temperature_max: Option<u8>,
+    /// `HUMIDITY_MAX` register (0x06), if read
+    pub humidity_max: Option<u8>,
+    /// `INTERRUPT_ENABLE` register (0x07)
+    pub interrupt_enable: u8,
+    /// `TEMP_OFFSET_ADJUST` register (0x08)
+    pub temperature_offset: u8,
+    /// `HUM_OFFSET_ADJUST` register (0x09)
+    pub humidity_offset: u8,
+    /// `TEMP_THR_L` register (0x0A)
+    pub temperature_threshold_low: u8,
+    /// `TEMP_THR_H` register (0x0B)
+    pub temperature_threshold_high: u8,
+    /// `RH_THR_L` register (0x0C)
+    pub humidity_threshold_low: u8,
+    /// `RH_THR_H` register (0x0D)
+    pub humidity_threshold_high: u8,
+    /// `DEVICE_CONFIG` register (0x0E)
+    pub device_config: u8,
+    /// `MEASUREMENT_CONFIG` register (0x0F)
+    pub measurement_config: u8,
+}
+
+impl Default for DeviceConfiguration {
     fn default() -> Self {
-        MeasurementMode::TemperatureAndHumidity
+        DeviceConfiguration {
+            temperature_max: None,
+            humidity_max: None,
+            interrupt_enable: 0,
+            temperature_offset: 0,
+            humidity_offset: 0,
+            temperature_threshold_low: 0x01,
+            temperature_threshold_high: 0xFF,
+            humidity_threshold_low: 0,
+            humidity_threshold_high: 0xFF,
+            device_config: 0,
+            measurement_config: 0,
+        }
+    }
+}
+
+impl DeviceConfiguration {
+    /// Configuration from the register contents starting at `INTERRUPT_ENABLE`
+    ///
+    /// The peak values are not included.
+    pub(crate) fn from_registers(data: [u8; 9]) -> Self {
+        DeviceConfiguration {
+            temperature_max: None,
+            humidity_max: None,
+            interrupt_enable: data[0],
+            temperature_offset: data[1],
+            humidity_offset: data[2],
+            temperature_threshold_low: data[3],
+            temperature_threshold_high: data[4],
+            humidity_threshold_low: data[5],
+            humidity_threshold_high: data[6],
+            device_config: data[7],
+            measurement_config: data[8],
+        }
+    }
+
+    /// Register contents starting at `INTERRUPT_ENABLE`
+    ///
+    /// The peak values are not included.
+    pub(crate) fn to_registers(self) -> [u8; 9] {
+        [
+            self.interrupt_enable,
+            self.temperature_offset,
+            self.humidity_offset,
+            self.temperature_threshold_low,
+            self.temperature_threshold_high,
+            self.humidity_threshold_low,
+            self.humidity_threshold_high,
+            self.device_config,
+            self.measurement_config,
+        ]
+    }
+}
+
+/// Contents of all device registers
+///
+/// Contains the registers from `TEMPERATURE_LOW` (0x00) to
+/// `MEASUREMENT_CONFIG` (0x0F) and the identification registers.
+///
+/// The `Display` implementation prints a decoded view with one line per
+/// register, naming every field and converting the measurements, peaks,
+/// offsets and thresholds into physical units.
+///
+/// See [`dump_registers()`](struct.Hdc20xx.html#method.dump_registers).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegisterDump {
+    /// Registers 0x00 to 0x0F
+    pub registers: [u8; 16],
+    /// Manufacturer ID (registers 0xFC and 0xFD)
+    pub manufacturer_id: u16,
+    /// Device ID (registers 0xFE and 0xFF)
+    pub device_id: u16,
+}
+
+/// Register names as in the datasheet, starting at 0x00
+const REGISTER_NAMES: [&str; 16] = [
+    "TEMPERATURE_LOW",
+    "TEMPERATURE_HIGH",
+    "HUMIDITY_LOW",
+    "HUMIDITY_HIGH",
+    "INTERRUPT_DRDY",
+    "TEMPERATURE_MAX",
+    "HUMIDITY_MAX",
+    "INTERRUPT_ENABLE",
+    "TEMP_OFFSET_ADJUST",
+    "HUM_OFFSET_ADJUST",
+    "TEMP_THR_L",
+    "TEMP_THR_H",
+    "RH_THR_L",
+    "RH_THR_H",
+    "DEVICE_CONFIG",
+    "MEASUREMENT_CONFIG",
+];
+
+impl RegisterDump {
+    /// Address, name and contents of every register
+    pub fn named_registers(&self) -> [(u8, &'static str, u8); 20] {
+        let [manufacturer_l, manufacturer_h] = self.manufacturer_id.to_le_bytes();
+        let [device_l, device_h] = self.device_id.to_le_bytes();
+        let ids = [
+            (
+                Register::MANUFACTURER_ID_L,
+                "MANUFACTURER_ID_LOW",
+                manufacturer_l,
+            ),
+            (
+                Register::MANUFACTURER_ID_L + 1,
+                "MANUFACTURER_ID_HIGH",
+                manufacturer_h,
+            ),
+            (Register::DEVICE_ID_L, "DEVICE_ID_LOW", device_l),
+            (Register::DEVICE_ID_L + 1, "DEVICE_ID_HIGH", device_h),
+        ];
+        core::array::from_fn(|i| match i {
+            0..=15 => (i as u8, REGISTER_NAMES[i], self.registers[i]),
+            _ => ids[i - 16],
+        })
+    }
+
+    /// Configuration registers
+    pub fn configuration(&self) -> DeviceConfiguration {
+        let mut data = [0; 9];
+        data.copy_from_slice(&self.registers[usize::from(Register::INTERRUPT_ENABLE)..]);
+        DeviceConfiguration {
+            temperature_max: Some(self.registers[usize::from(Register::TEMP_MAX)]),
+            humidity_max: Some(self.registers[usize::from(Register::HUM_MAX)]),
+            ..DeviceConfiguration::from_registers(data)
+        }
+    }
+
+    fn fmt_decoded(&self, f: &mut fmt::Formatter<'_>, register: u8, value: u8) -> fmt::Result {
+        let flag = |mask: u8| u8::from(value & mask != 0);
+        match register {
+            0x01 => {
+                let raw = u16::from_le_bytes([self.registers[0], value]);
+                write!(
+                    f,
+                    "temperature {:.2}°C",
+                    conversion::temperature_from_raw(raw)
+                )
+            }
+            0x03 => {
+                let raw = u16::from_le_bytes([self.registers[2], value]);
+                write!(f, "humidity {:.2}%RH", conversion::humidity_from_raw(raw))
+            }
+            Register::DRDY => write!(
+                f,
+                "DRDY_STATUS={} TH_STATUS={} TL_STATUS={} HH_STATUS={} HL_STATUS={}",
+                flag(BitFlags::DRDY_STATUS),
+                flag(BitFlags::TH_STATUS),
+                flag(BitFlags::TL_STATUS),
+                flag(BitFlags::HH_STATUS),
+                flag(BitFlags::HL_STATUS)
+            ),
+            Register::TEMP_MAX | Register::TEMP_THR_L | Register::TEMP_THR_H => {
+                write!(f, "{:.2}°C", conversion::temperature_from_u8(value))
+            }
+            Register::HUM_MAX | Register::RH_THR_L | Register::RH_THR_H => {
+                write!(f, "{:.2}%RH", conversion::humidity_from_u8(value))
+            }
+            Register::INTERRUPT_ENABLE => write!(
+                f,
+                "DRDY_ENABLE={} TH_ENABLE={} TL_ENABLE={} HH_ENABLE={} HL_ENABLE={}",
+                flag(BitFlags::DRDY_ENABLE),
+                flag(BitFlags::TH_ENABLE),
+                flag(BitFlags::TL_ENABLE),
+                flag(BitFlags::HH_ENABLE),
+                flag(BitFlags::HL_ENABLE)
+            ),
+            Register::TEMP_OFFSET_ADJUST => write!(
+                f,
+                "{:+.2}°C",
+                conversion::temperature_offset_from_code(value)
+            ),
+            Register::HUM_OFFSET_ADJUST => {
+                write!(f, "{:+.2}%RH", conversion::humidity_offset_from_code(value))
+            }
+            Register::DEVICE_CONFIG => {
+                let amm = match (value & BitFlags::AMM_MASK) >> 4 {
+                    0 => "disabled",
+                    1 => "1/120 Hz",
+                    2 => "1/60 Hz",
+                    3 => "0.1 Hz",
+                    4 => "0.2 Hz",
+                    5 => "1 Hz",
+                    6 => "2 Hz",
+                    _ => "5 Hz",
+                };
+                let polarity = if value & BitFlags::INT_POL != 0 {
+                    "active high"
+                } else {
+                    "active low"
+                };
+                let mode = if value & BitFlags::INT_MODE != 0 {
+                    "comparator"
+                } else {
+                    "level sensitive"
+                };
+                write!(
+                    f,
+                    "SOFT_RES={} AMM={} HEAT_EN={} INT_EN={} INT_POL={} INT_MODE={}",
+                    flag(BitFlags::SOFT_RESET),
+                    amm,
+                    flag(BitFlags::HEAT_EN),
+                    flag(BitFlags::INT_EN),
+                    polarity,
+                    mode
+                )
+            }
+            Register::MEAS_CONF => {
+                let channels = if value & BitFlags::TEMP_ONLY != 0 {
+                    "temperature only"
+                } else {
+                    "temperature and humidity"
+                };
+                write!(
+                    f,
+                    "TRES={} bits HRES={} bits MEAS_CONF={} MEAS_TRIG={}",
+                    Resolution::from_bits(value >> 6).bit_count(),
+                    Resolution::from_bits(value >> 4).bit_count(),
+                    channels,
+                    flag(BitFlags::MEAS_TRIG)
+                )
+            }
+            r if r == Register::MANUFACTURER_ID_L + 1 => {
+                write!(f, "manufacturer ID 0x{:04X}", self.manufacturer_id)
+            }
+            r if r == Register::DEVICE_ID_L + 1 => {
+                write!(f, "device ID 0x{:04X}", self.device_id)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for RegisterDump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (register, name, value) in self.named_registers().iter() {
+            write!(f, "0x{:02X} {:<20} 0x{:02X}", register, name, value)?;
+            // The low bytes are decoded together with the high bytes.
+            if !matches!(*register, 0x00 | 0x02 | 0xFC | 0xFE) {
+                f.write_str("  ")?;
+                self.fmt_decoded(f, *register, *value)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
     }
 }
 
 /// Possible slave addresses
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SlaveAddr {
     /// Default slave address
     Default,
@@ -81,7 +665,10 @@ impl SlaveAddr {
 #[cfg(test)]
 mod tests {
     use super::BASE_ADDR as ADDR;
-    use super::{MeasurementMode, SlaveAddr};
+    use super::{
+        InterruptConfig, InterruptMode, InterruptPolarity, MeasurementMode, Resolution, SlaveAddr,
+        Variant,
+    };
 
     #[test]
     fn can_get_default_address() {
@@ -102,4 +689,39 @@ mod tests {
             MeasurementMode::default()
         );
     }
+
+    #[test]
+    fn can_get_variant_name() {
+        assert_eq!("HDC2010", Variant::Hdc2010.name());
+        assert_eq!("HDC2021", Variant::Hdc2021.name());
+        assert_eq!("HDC2080", Variant::Hdc2080.name());
+    }
+
+    #[test]
+    fn can_get_default_resolution() {
+        assert_eq!(Resolution::Bits14, Resolution::default());
+    }
+
+    #[test]
+    fn can_convert_resolution_bits() {
+        for res in &[Resolution::Bits14, Resolution::Bits11, Resolution::Bits9] {
+            assert_eq!(*res, Resolution::from_bits(res.bits()));
+        }
+    }
+
+    #[test]
+    fn can_get_default_interrupt_config() {
+        assert_eq!(
+            InterruptConfig {
+                data_ready: false,
+                high_temperature: false,
+                low_temperature: false,
+                high_humidity: false,
+                low_humidity: false,
+                polarity: InterruptPolarity::ActiveLow,
+                mode: InterruptMode::LevelSensitive,
+            },
+            InterruptConfig::default()
+        );
+    }
 }
@@ -0,0 +1,125 @@
+//! Typed physical units.
+//!
+//! The [`Celsius`] and [`RelativeHumidity`] newtypes make the unit of a
+//! value explicit and provide conversions into other units.
+//!
+//! With the `uom` feature enabled, the measurements can additionally be
+//! obtained as [`uom`] quantities. See
+//! [`Measurement::thermodynamic_temperature()`](../struct.Measurement.html#method.thermodynamic_temperature).
+//!
+//! [`uom`]: https://crates.io/crates/uom
+
+use crate::Measurement;
+
+/// Temperature in degrees Celsius (°C)
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Celsius(pub f32);
+
+impl Celsius {
+    /// Temperature in degrees Celsius (°C)
+    pub fn celsius(self) -> f32 {
+        self.0
+    }
+
+    /// Temperature in degrees Fahrenheit (°F)
+    pub fn fahrenheit(self) -> f32 {
+        self.0 * 1.8 + 32.0
+    }
+
+    /// Temperature in Kelvin (K)
+    pub fn kelvin(self) -> f32 {
+        self.0 + 273.15
+    }
+}
+
+/// Relative humidity in percent (%RH)
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelativeHumidity(pub f32);
+
+impl RelativeHumidity {
+    /// Relative humidity in percent (%RH)
+    pub fn percent(self) -> f32 {
+        self.0
+    }
+
+    /// Relative humidity as a fraction between 0.0 and 1.0
+    pub fn fraction(self) -> f32 {
+        self.0 / 100.0
+    }
+}
+
+impl Measurement {
+    /// Temperature as [`Celsius`]
+    pub fn temperature_celsius(&self) -> Celsius {
+        Celsius(self.temperature)
+    }
+
+    /// Relative humidity as [`RelativeHumidity`]
+    ///
+    /// Returns `None` if the humidity was not measured.
+    pub fn relative_humidity(&self) -> Option<RelativeHumidity> {
+        self.humidity.map(RelativeHumidity)
+    }
+}
+
+#[cfg(feature = "uom")]
+mod uom_impl {
+    use super::{Celsius, RelativeHumidity};
+    use crate::Measurement;
+    use uom::si::{
+        f32::{Ratio, ThermodynamicTemperature},
+        ratio::percent,
+        thermodynamic_temperature::degree_celsius,
+    };
+
+    impl From<Celsius> for ThermodynamicTemperature {
+        fn from(temperature: Celsius) -> Self {
+            ThermodynamicTemperature::new::<degree_celsius>(temperature.0)
+        }
+    }
+
+    impl From<RelativeHumidity> for Ratio {
+        fn from(humidity: RelativeHumidity) -> Self {
+            Ratio::new::<percent>(humidity.0)
+        }
+    }
+
+    impl Measurement {
+        /// Temperature as a `uom` quantity
+        pub fn thermodynamic_temperature(&self) -> ThermodynamicTemperature {
+            self.temperature_celsius().into()
+        }
+
+        /// Relative humidity as a `uom` ratio
+        ///
+        /// Returns `None` if the humidity was not measured.
+        pub fn humidity_ratio(&self) -> Option<Ratio> {
+            self.relative_humidity().map(Ratio::from)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_convert_temperature() {
+        let temperature = Celsius(25.0);
+        assert_eq!(25.0, temperature.celsius());
+        assert_eq!(77.0, temperature.fahrenheit());
+        assert_eq!(298.15, temperature.kelvin());
+        assert_eq!(-40.0, Celsius(-40.0).fahrenheit());
+    }
+
+    #[test]
+    fn can_convert_humidity() {
+        let humidity = RelativeHumidity(50.0);
+        assert_eq!(50.0, humidity.percent());
+        assert_eq!(0.5, humidity.fraction());
+    }
+}
@@ -0,0 +1,218 @@
+#![cfg(feature = "async")]
+mod common;
+use crate::common::{BitFlags as BF, Register, BASE_ADDR};
+use embassy_futures::block_on;
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    digital::{Mock as PinMock, State, Transaction as PinTrans},
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+use hdc20xx::{
+    AsyncHdc20xx, Error, InterruptConfig, InterruptPolarity, MeasurementMode, Resolution, SlaveAddr,
+};
+
+fn new(transactions: &[I2cTrans]) -> AsyncHdc20xx<I2cMock> {
+    AsyncHdc20xx::new(I2cMock::new(transactions), SlaveAddr::default())
+}
+
+fn destroy(sensor: AsyncHdc20xx<I2cMock>) {
+    sensor.destroy().done();
+}
+
+#[test]
+fn can_create_and_destroy() {
+    let sensor = new(&[]);
+    destroy(sensor);
+}
+
+#[test]
+fn can_get_ids() {
+    let mut sensor = new(&[
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DEVICE_ID_L], vec![0xD0, 0x07]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::MANUFACTURER_ID_L],
+            vec![0x49, 0x54],
+        ),
+    ]);
+    assert_eq!(0x07D0, block_on(sensor.device_id()).unwrap());
+    assert_eq!(0x5449, block_on(sensor.manufacturer_id()).unwrap());
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_status() {
+    let mut sensor = new(&[I2cTrans::write_read(
+        BASE_ADDR,
+        vec![Register::DRDY],
+        vec![BF::DRDY_STATUS | BF::TH_STATUS],
+    )]);
+    let status = block_on(sensor.status()).unwrap();
+    assert!(status.data_ready);
+    assert!(status.high_temp_threshold_exceeded);
+    assert!(!status.low_temp_threshold_exceeded);
+    destroy(sensor);
+}
+
+#[test]
+fn can_measure_temp_and_humidity() {
+    let mut sensor = new(&[
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![0]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91],
+        ),
+    ]);
+    let data = block_on(sensor.measure(&mut NoopDelay::new())).unwrap();
+    assert!(data.temperature < 25.5);
+    assert!(data.temperature > 24.5);
+    let rh = data.humidity.unwrap();
+    assert!(rh < 57.5);
+    assert!(rh > 56.5);
+    assert!(data.status.data_ready);
+    destroy(sensor);
+}
+
+#[test]
+fn can_measure_temp_only_with_resolution() {
+    let mut sensor = new(&[
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::TEMP_ONLY]),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::MEAS_CONF, BF::TEMP_ONLY | BF::TRES_9],
+        ),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![
+                Register::MEAS_CONF,
+                BF::TEMP_ONLY | BF::TRES_9 | BF::HRES_11,
+            ],
+        ),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![
+                Register::MEAS_CONF,
+                BF::TEMP_ONLY | BF::TRES_9 | BF::HRES_11 | BF::MEAS_TRIG,
+            ],
+        ),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::TEMP_L], vec![0xD9, 0x64]),
+    ]);
+    block_on(sensor.set_measurement_mode(MeasurementMode::TemperatureOnly)).unwrap();
+    block_on(sensor.set_temperature_resolution(Resolution::Bits9)).unwrap();
+    block_on(sensor.set_humidity_resolution(Resolution::Bits11)).unwrap();
+    assert_eq!(225, sensor.conversion_time_us());
+    let data = block_on(sensor.measure(&mut NoopDelay::new())).unwrap();
+    assert!(data.temperature < 25.5);
+    assert!(data.temperature > 24.5);
+    assert!(data.humidity.is_none());
+    destroy(sensor);
+}
+
+#[test]
+fn can_measure_with_active_low_drdy_pin() {
+    let mut sensor = new(&[
+        I2cTrans::write(BASE_ADDR, vec![Register::INTERRUPT_ENABLE, BF::DRDY_ENABLE]),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, BF::INT_EN]),
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91, BF::DRDY_STATUS],
+        ),
+    ]);
+    let mut pin = PinMock::new(&[PinTrans::wait_for_state(State::Low)]);
+    block_on(sensor.set_interrupt_config(InterruptConfig {
+        data_ready: true,
+        ..Default::default()
+    }))
+    .unwrap();
+    let data = block_on(sensor.measure_with_drdy_pin(&mut pin)).unwrap();
+    assert!(data.temperature < 25.5);
+    assert!(data.temperature > 24.5);
+    let rh = data.humidity.unwrap();
+    assert!(rh < 57.5);
+    assert!(rh > 56.5);
+    assert!(data.status.data_ready);
+    destroy(sensor);
+    pin.done();
+}
+
+#[test]
+fn can_measure_with_active_high_drdy_pin() {
+    let mut sensor = new(&[
+        I2cTrans::write(BASE_ADDR, vec![Register::INTERRUPT_ENABLE, BF::DRDY_ENABLE]),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::DEVICE_CONFIG, BF::INT_EN | BF::INT_POL],
+        ),
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91, BF::DRDY_STATUS],
+        ),
+    ]);
+    let mut pin = PinMock::new(&[PinTrans::wait_for_state(State::High)]);
+    block_on(sensor.set_interrupt_config(InterruptConfig {
+        data_ready: true,
+        polarity: InterruptPolarity::ActiveHigh,
+        ..Default::default()
+    }))
+    .unwrap();
+    let data = block_on(sensor.measure_with_drdy_pin(&mut pin)).unwrap();
+    assert!(data.temperature < 25.5);
+    assert!(data.temperature > 24.5);
+    destroy(sensor);
+    pin.done();
+}
+
+#[test]
+fn waits_again_for_drdy_pin_without_data_ready() {
+    let mut sensor = new(&[
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0, 0, 0, 0, BF::TH_STATUS],
+        ),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91, BF::DRDY_STATUS],
+        ),
+    ]);
+    let mut pin = PinMock::new(&[
+        PinTrans::wait_for_state(State::Low),
+        PinTrans::wait_for_state(State::Low),
+    ]);
+    let data = block_on(sensor.measure_with_drdy_pin(&mut pin)).unwrap();
+    assert!(data.status.data_ready);
+    assert!(data.temperature > 24.5);
+    destroy(sensor);
+    pin.done();
+}
+
+#[test]
+fn measure_times_out() {
+    let mut transactions = vec![I2cTrans::write(
+        BASE_ADDR,
+        vec![Register::MEAS_CONF, BF::MEAS_TRIG],
+    )];
+    for _ in 0..10 {
+        transactions.push(I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::DRDY],
+            vec![0],
+        ));
+    }
+    let mut sensor = new(&transactions);
+    match block_on(sensor.measure(&mut NoopDelay::new())) {
+        Err(Error::Timeout) => (),
+        _ => panic!("Timeout not returned."),
+    }
+    destroy(sensor);
+}
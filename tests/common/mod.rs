@@ -1,5 +1,5 @@
-use embedded_hal_mock::i2c::{Mock as I2cMock, Transaction as I2cTrans};
-use hdc20xx::{mode, Hdc20xx, SlaveAddr};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+use hdc20xx::{mode, Hdc20xx, Measurement, SlaveAddr, Status};
 
 pub struct Register;
 #[allow(unused)]
@@ -7,6 +7,16 @@ impl Register {
     pub const TEMP_L: u8 = 0x00;
     pub const HUMIDITY_L: u8 = 0x02;
     pub const DRDY: u8 = 0x04;
+    pub const TEMP_MAX: u8 = 0x05;
+    pub const HUM_MAX: u8 = 0x06;
+    pub const INTERRUPT_ENABLE: u8 = 0x07;
+    pub const TEMP_OFFSET_ADJUST: u8 = 0x08;
+    pub const HUM_OFFSET_ADJUST: u8 = 0x09;
+    pub const TEMP_THR_L: u8 = 0x0A;
+    pub const TEMP_THR_H: u8 = 0x0B;
+    pub const RH_THR_L: u8 = 0x0C;
+    pub const RH_THR_H: u8 = 0x0D;
+    pub const DEVICE_CONFIG: u8 = 0x0E;
     pub const MEAS_CONF: u8 = 0x0F;
     pub const MANUFACTURER_ID_L: u8 = 0xFC;
     pub const DEVICE_ID_L: u8 = 0xFE;
@@ -16,6 +26,14 @@ pub struct BitFlags;
 #[allow(unused)]
 impl BitFlags {
     pub const SOFT_RESET: u8 = 1 << 7;
+    pub const HEAT_EN: u8 = 1 << 3;
+    pub const INT_EN: u8 = 1 << 2;
+    pub const INT_POL: u8 = 1 << 1;
+    pub const INT_MODE: u8 = 1;
+    pub const TRES_11: u8 = 0b0100_0000;
+    pub const TRES_9: u8 = 0b1000_0000;
+    pub const HRES_11: u8 = 0b0001_0000;
+    pub const HRES_9: u8 = 0b0010_0000;
     pub const TEMP_ONLY: u8 = 1 << 1;
     pub const MEAS_TRIG: u8 = 1;
     pub const DRDY_STATUS: u8 = 1 << 7;
@@ -23,7 +41,13 @@ impl BitFlags {
     pub const TL_STATUS: u8 = 1 << 5;
     pub const HH_STATUS: u8 = 1 << 4;
     pub const HL_STATUS: u8 = 1 << 3;
+    pub const DRDY_ENABLE: u8 = 1 << 7;
+    pub const TH_ENABLE: u8 = 1 << 6;
+    pub const TL_ENABLE: u8 = 1 << 5;
+    pub const HH_ENABLE: u8 = 1 << 4;
+    pub const HL_ENABLE: u8 = 1 << 3;
 }
+#[allow(unused)]
 pub const BASE_ADDR: u8 = 0x40;
 
 #[allow(unused)]
@@ -35,3 +59,20 @@ pub fn new(transactions: &[I2cTrans]) -> Hdc20xx<I2cMock, mode::OneShot> {
 pub fn destroy<MODE>(sensor: Hdc20xx<I2cMock, MODE>) {
     sensor.destroy().done();
 }
+
+#[allow(unused)]
+pub fn measurement(temperature: f32, humidity: Option<f32>) -> Measurement {
+    Measurement {
+        temperature,
+        humidity,
+        max_temperature: None,
+        max_humidity: None,
+        status: Status {
+            data_ready: true,
+            high_temp_threshold_exceeded: false,
+            low_temp_threshold_exceeded: false,
+            high_humidity_threshold_exceeded: false,
+            low_humidity_threshold_exceeded: false,
+        },
+    }
+}
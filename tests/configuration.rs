@@ -0,0 +1,245 @@
+mod common;
+use crate::common::{destroy, new, BitFlags as BF, Register, BASE_ADDR};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+use hdc20xx::{DeviceConfiguration, Resolution, SamplingRate};
+
+const DEFAULT_REGISTERS: [u8; 9] = [0, 0, 0, 0x01, 0xFF, 0, 0xFF, 0, 0];
+
+fn read_configuration(registers: &[u8]) -> I2cTrans {
+    I2cTrans::write_read(
+        BASE_ADDR,
+        vec![Register::INTERRUPT_ENABLE],
+        registers.to_vec(),
+    )
+}
+
+#[test]
+fn can_read_configuration() {
+    let transactions = [read_configuration(&[
+        BF::DRDY_ENABLE,
+        0x12,
+        0x34,
+        0x56,
+        0x78,
+        0x9A,
+        0xBC,
+        BF::INT_EN,
+        BF::TEMP_ONLY,
+    ])];
+    let mut sensor = new(&transactions);
+    let config = sensor.read_configuration().unwrap();
+    assert_eq!(
+        DeviceConfiguration {
+            temperature_max: None,
+            humidity_max: None,
+            interrupt_enable: BF::DRDY_ENABLE,
+            temperature_offset: 0x12,
+            humidity_offset: 0x34,
+            temperature_threshold_low: 0x56,
+            temperature_threshold_high: 0x78,
+            humidity_threshold_low: 0x9A,
+            humidity_threshold_high: 0xBC,
+            device_config: BF::INT_EN,
+            measurement_config: BF::TEMP_ONLY,
+        },
+        config
+    );
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_configuration_with_peaks() {
+    let mut registers = vec![0x66, 0x99];
+    registers.extend_from_slice(&DEFAULT_REGISTERS);
+    let transactions = [I2cTrans::write_read(
+        BASE_ADDR,
+        vec![Register::TEMP_MAX],
+        registers,
+    )];
+    let mut sensor = new(&transactions);
+    sensor.enable_peak_readout();
+    let config = sensor.read_configuration().unwrap();
+    assert_eq!(
+        DeviceConfiguration {
+            temperature_max: Some(0x66),
+            humidity_max: Some(0x99),
+            ..Default::default()
+        },
+        config
+    );
+    destroy(sensor);
+}
+
+#[test]
+fn applying_configuration_does_not_read_or_write_peaks() {
+    let transactions = [read_configuration(&DEFAULT_REGISTERS)];
+    let mut sensor = new(&transactions);
+    sensor.enable_peak_readout();
+    let config = DeviceConfiguration {
+        temperature_max: Some(0x66),
+        humidity_max: Some(0x99),
+        ..Default::default()
+    };
+    sensor.apply_configuration(&config).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn default_configuration_is_reset_state() {
+    let transactions = [read_configuration(&DEFAULT_REGISTERS)];
+    let mut sensor = new(&transactions);
+    let config = sensor.read_configuration().unwrap();
+    assert_eq!(DeviceConfiguration::default(), config);
+    destroy(sensor);
+}
+
+#[test]
+fn applying_same_configuration_writes_nothing() {
+    let transactions = [read_configuration(&DEFAULT_REGISTERS)];
+    let mut sensor = new(&transactions);
+    sensor
+        .apply_configuration(&DeviceConfiguration::default())
+        .unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn applying_configuration_writes_only_differing_registers() {
+    let transactions = [
+        read_configuration(&DEFAULT_REGISTERS),
+        I2cTrans::write(BASE_ADDR, vec![Register::TEMP_OFFSET_ADJUST, 0x12]),
+        I2cTrans::write(BASE_ADDR, vec![Register::RH_THR_H, 0x80]),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, BF::HEAT_EN]),
+    ];
+    let mut sensor = new(&transactions);
+    let config = DeviceConfiguration {
+        temperature_offset: 0x12,
+        humidity_threshold_high: 0x80,
+        device_config: BF::HEAT_EN,
+        ..Default::default()
+    };
+    sensor.apply_configuration(&config).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn applying_configuration_ignores_trigger_and_reset_bits() {
+    let transactions = [read_configuration(&DEFAULT_REGISTERS)];
+    let mut sensor = new(&transactions);
+    let config = DeviceConfiguration {
+        device_config: BF::SOFT_RESET,
+        measurement_config: BF::MEAS_TRIG,
+        ..Default::default()
+    };
+    sensor.apply_configuration(&config).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn applying_configuration_keeps_auto_measurement_mode() {
+    let mut current = DEFAULT_REGISTERS;
+    current[7] = 5 << 4;
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, 5 << 4]),
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        read_configuration(&current),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::DEVICE_CONFIG, (5 << 4) | BF::INT_EN],
+        ),
+        // The cached configuration is updated
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, BF::INT_EN]),
+    ];
+    let sensor = new(&transactions);
+    let mut sensor = sensor.into_continuous(SamplingRate::Hz1).ok().unwrap();
+    let config = DeviceConfiguration {
+        device_config: (7 << 4) | BF::INT_EN,
+        ..Default::default()
+    };
+    sensor.apply_configuration(&config).unwrap();
+    let sensor = sensor.into_one_shot().ok().unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn applying_configuration_updates_measurement_configuration() {
+    let transactions = [
+        read_configuration(&DEFAULT_REGISTERS),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::MEAS_CONF, BF::TRES_11 | BF::TEMP_ONLY],
+        ),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![
+                Register::MEAS_CONF,
+                BF::TRES_11 | BF::TEMP_ONLY | BF::MEAS_TRIG,
+            ],
+        ),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::TEMP_L], vec![0xD9, 0x64]),
+    ];
+    let mut sensor = new(&transactions);
+    let config = DeviceConfiguration {
+        measurement_config: BF::TRES_11 | BF::TEMP_ONLY,
+        ..Default::default()
+    };
+    sensor.apply_configuration(&config).unwrap();
+    sensor.read().expect_err("should block");
+    let data = sensor.read().unwrap();
+    assert!(data.humidity.is_none());
+    destroy(sensor);
+}
+
+#[test]
+fn can_load_configuration_without_writing() {
+    let mut current = DEFAULT_REGISTERS;
+    current[1] = 0x12;
+    current[7] = BF::INT_EN | BF::SOFT_RESET;
+    current[8] = BF::TEMP_ONLY | BF::MEAS_TRIG;
+    let transactions = [
+        read_configuration(&current),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::DEVICE_CONFIG, BF::INT_EN | BF::HEAT_EN],
+        ),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::MEAS_CONF, BF::TRES_11 | BF::TEMP_ONLY],
+        ),
+    ];
+    let mut sensor = new(&transactions);
+    let config = sensor.load_configuration().unwrap();
+    assert_eq!(
+        DeviceConfiguration {
+            temperature_offset: 0x12,
+            device_config: BF::INT_EN | BF::SOFT_RESET,
+            measurement_config: BF::TEMP_ONLY | BF::MEAS_TRIG,
+            ..Default::default()
+        },
+        config
+    );
+    sensor.enable_heater().unwrap();
+    sensor
+        .set_temperature_resolution(Resolution::Bits11)
+        .unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn loading_configuration_does_not_take_over_auto_measurement_mode() {
+    let mut current = DEFAULT_REGISTERS;
+    current[7] = (5 << 4) | BF::INT_EN;
+    let transactions = [
+        read_configuration(&current),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::DEVICE_CONFIG, BF::INT_EN | BF::HEAT_EN],
+        ),
+    ];
+    let mut sensor = new(&transactions);
+    let config = sensor.load_configuration().unwrap();
+    assert_eq!((5 << 4) | BF::INT_EN, config.device_config);
+    sensor.enable_heater().unwrap();
+    destroy(sensor);
+}
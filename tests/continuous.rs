@@ -0,0 +1,117 @@
+mod common;
+use crate::common::{destroy, new, BitFlags as BF, Register, BASE_ADDR};
+use embedded_hal::i2c::ErrorKind;
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+use hdc20xx::{MeasurementMode, ModeChangeError, SamplingRate};
+
+macro_rules! into_continuous_test {
+    ($name:ident, $rate:ident, $amm:expr) => {
+        #[test]
+        fn $name() {
+            let transactions = [
+                I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, $amm << 4]),
+                I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+            ];
+            let sensor = new(&transactions);
+            let sensor = sensor.into_continuous(SamplingRate::$rate).ok().unwrap();
+            destroy(sensor);
+        }
+    };
+}
+
+into_continuous_test!(into_cont_1_120hz, OnePer120s, 1);
+into_continuous_test!(into_cont_1_60hz, OnePer60s, 2);
+into_continuous_test!(into_cont_0_1hz, OnePer10s, 3);
+into_continuous_test!(into_cont_0_2hz, OnePer5s, 4);
+into_continuous_test!(into_cont_1hz, Hz1, 5);
+into_continuous_test!(into_cont_2hz, Hz2, 6);
+into_continuous_test!(into_cont_5hz, Hz5, 7);
+
+#[test]
+fn failed_trigger_restores_sampling_rate() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, 5 << 4]),
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG])
+            .with_error(ErrorKind::Other),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, 0]),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, BF::HEAT_EN]),
+    ];
+    let sensor = new(&transactions);
+    let mut sensor = match sensor.into_continuous(SamplingRate::Hz1) {
+        Err(ModeChangeError::I2C(ErrorKind::Other, sensor)) => sensor,
+        _ => panic!("expected I2C error"),
+    };
+    sensor.enable_heater().unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_change_into_one_shot() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, 5 << 4]),
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, 0]),
+    ];
+    let sensor = new(&transactions);
+    let sensor = sensor.into_continuous(SamplingRate::Hz1).ok().unwrap();
+    let sensor = sensor.into_one_shot().ok().unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_in_continuous_mode_temp_and_humidity() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, 7 << 4]),
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91],
+        ),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![0]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91],
+        ),
+    ];
+    let sensor = new(&transactions);
+    let mut sensor = sensor.into_continuous(SamplingRate::Hz5).ok().unwrap();
+    let data = sensor.read().unwrap();
+    assert!(data.temperature < 25.5);
+    assert!(data.temperature > 24.5);
+    let rh = data.humidity.unwrap();
+    assert!(rh < 57.5);
+    assert!(rh > 56.5);
+    assert!(data.status.data_ready);
+    let data = sensor.read().unwrap();
+    assert!(data.temperature < 25.5);
+    assert!(data.temperature > 24.5);
+    assert!(!data.status.data_ready);
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_in_continuous_mode_temp_only() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::TEMP_ONLY]),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, 5 << 4]),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::MEAS_CONF, BF::TEMP_ONLY | BF::MEAS_TRIG],
+        ),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::TEMP_L], vec![0xD9, 0x64]),
+    ];
+    let mut sensor = new(&transactions);
+    sensor
+        .set_measurement_mode(MeasurementMode::TemperatureOnly)
+        .unwrap();
+    let mut sensor = sensor.into_continuous(SamplingRate::Hz1).ok().unwrap();
+    let data = sensor.read().unwrap();
+    assert!(data.temperature < 25.5);
+    assert!(data.temperature > 24.5);
+    assert!(data.humidity.is_none());
+    destroy(sensor);
+}
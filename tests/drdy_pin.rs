@@ -0,0 +1,102 @@
+mod common;
+use crate::common::{destroy, new, BitFlags as BF, Register, BASE_ADDR};
+use embedded_hal_mock::eh1::{
+    digital::{Mock as PinMock, State, Transaction as PinTrans},
+    i2c::Transaction as I2cTrans,
+    MockError,
+};
+use hdc20xx::{Error, InterruptConfig, InterruptPolarity, MeasurementMode};
+
+#[test]
+fn can_read_with_active_low_pin() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91, BF::DRDY_STATUS],
+        ),
+    ];
+    let mut pin = PinMock::new(&[PinTrans::get(State::High), PinTrans::get(State::Low)]);
+    let mut sensor = new(&transactions);
+    sensor
+        .read_with_drdy_pin(&mut pin)
+        .expect_err("should block");
+    sensor
+        .read_with_drdy_pin(&mut pin)
+        .expect_err("should block");
+    let data = sensor.read_with_drdy_pin(&mut pin).unwrap();
+    assert!(data.temperature < 25.5);
+    assert!(data.temperature > 24.5);
+    let rh = data.humidity.unwrap();
+    assert!(rh < 57.5);
+    assert!(rh > 56.5);
+    assert!(data.status.data_ready);
+    destroy(sensor);
+    pin.done();
+}
+
+#[test]
+fn can_read_with_active_high_pin_temp_only_and_peaks() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::INTERRUPT_ENABLE, BF::DRDY_ENABLE]),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::DEVICE_CONFIG, BF::INT_EN | BF::INT_POL],
+        ),
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::TEMP_ONLY]),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::MEAS_CONF, BF::TEMP_ONLY | BF::MEAS_TRIG],
+        ),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0, 0, BF::DRDY_STATUS, 128, 0],
+        ),
+    ];
+    let mut pin = PinMock::new(&[PinTrans::get(State::High)]);
+    let mut sensor = new(&transactions);
+    sensor
+        .set_interrupt_config(InterruptConfig {
+            data_ready: true,
+            polarity: InterruptPolarity::ActiveHigh,
+            ..Default::default()
+        })
+        .unwrap();
+    sensor
+        .set_measurement_mode(MeasurementMode::TemperatureOnly)
+        .unwrap();
+    sensor.enable_peak_readout();
+    sensor
+        .read_with_drdy_pin(&mut pin)
+        .expect_err("should block");
+    let data = sensor.read_with_drdy_pin(&mut pin).unwrap();
+    assert!(data.temperature < 25.5);
+    assert!(data.temperature > 24.5);
+    assert!(data.humidity.is_none());
+    assert!((data.max_temperature.unwrap() - 42.5).abs() < 0.01);
+    assert!(data.max_humidity.is_none());
+    destroy(sensor);
+    pin.done();
+}
+
+#[test]
+fn returns_pin_error() {
+    let transactions = [I2cTrans::write(
+        BASE_ADDR,
+        vec![Register::MEAS_CONF, BF::MEAS_TRIG],
+    )];
+    let error = MockError::Io(std::io::ErrorKind::NotConnected);
+    let mut pin = PinMock::new(&[PinTrans::get(State::Low).with_error(error)]);
+    let mut sensor = new(&transactions);
+    sensor
+        .read_with_drdy_pin(&mut pin)
+        .expect_err("should block");
+    match sensor.read_with_drdy_pin(&mut pin) {
+        Err(nb::Error::Other(Error::Pin(embedded_hal::digital::ErrorKind::Other))) => (),
+        _ => panic!("Pin error not returned."),
+    }
+    destroy(sensor);
+    pin.done();
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "eh02")]
+mod common;
+use crate::common::{BitFlags as BF, Register, BASE_ADDR};
+use embedded_hal::i2c::{I2c, Operation};
+use embedded_hal_0_2::blocking::i2c as i2c_0_2;
+use embedded_hal_mock::eh0::{
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+    MockError,
+};
+use hdc20xx::{Hdc20xx, I2cCompat, I2cCompatError, SlaveAddr};
+
+fn new(transactions: &[I2cTrans]) -> Hdc20xx<I2cCompat<I2cMock>, hdc20xx::mode::OneShot> {
+    Hdc20xx::new(
+        I2cCompat::new(I2cMock::new(transactions)),
+        SlaveAddr::default(),
+    )
+}
+
+fn destroy<MODE>(sensor: Hdc20xx<I2cCompat<I2cMock>, MODE>) {
+    sensor.destroy().into_inner().done();
+}
+
+#[test]
+fn can_get_device_id() {
+    let mut sensor = new(&[I2cTrans::write_read(
+        BASE_ADDR,
+        vec![Register::DEVICE_ID_L],
+        vec![0xD0, 0x07],
+    )]);
+    assert_eq!(0x07D0, sensor.device_id().unwrap());
+    destroy(sensor);
+}
+
+#[test]
+fn can_make_one_shot_measurement() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91],
+        ),
+    ];
+    let mut sensor = new(&transactions);
+    sensor.read().expect_err("should block");
+    let data = sensor.read().unwrap();
+    assert!(data.temperature < 25.5);
+    assert!(data.temperature > 24.5);
+    destroy(sensor);
+}
+
+/// Bus implementing only the traits required by the `embedded-hal` 0.2 driver
+struct WriteReadBus(I2cMock);
+
+impl i2c_0_2::Write for WriteReadBus {
+    type Error = MockError;
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.0.write(address, bytes)
+    }
+}
+
+impl i2c_0_2::WriteRead for WriteReadBus {
+    type Error = MockError;
+
+    fn write_read(
+        &mut self,
+        address: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.0.write_read(address, bytes, buffer)
+    }
+}
+
+#[test]
+fn bus_does_not_need_read() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91],
+        ),
+    ];
+    let bus = WriteReadBus(I2cMock::new(&transactions));
+    let mut sensor = Hdc20xx::new(I2cCompat::new(bus), SlaveAddr::default());
+    sensor.read().expect_err("should block");
+    assert!(sensor.read().unwrap().status.data_ready);
+    sensor.destroy().into_inner().0.done();
+}
+
+#[test]
+fn executes_write_and_read_operations_as_write_read() {
+    let mut i2c = I2cCompat::new(I2cMock::new(&[I2cTrans::write_read(
+        BASE_ADDR,
+        vec![Register::DRDY],
+        vec![BF::DRDY_STATUS],
+    )]));
+    let mut data = [0];
+    i2c.transaction(
+        BASE_ADDR,
+        &mut [
+            Operation::Write(&[Register::DRDY]),
+            Operation::Read(&mut data),
+        ],
+    )
+    .unwrap();
+    assert_eq!([BF::DRDY_STATUS], data);
+    i2c.into_inner().done();
+}
+
+#[test]
+fn rejects_read_without_write() {
+    let mut i2c = I2cCompat::new(I2cMock::new(&[]));
+    let mut data = [0];
+    match i2c.read(BASE_ADDR, &mut data) {
+        Err(I2cCompatError::UnsupportedRead) => (),
+        _ => panic!("expected unsupported read"),
+    }
+    i2c.into_inner().done();
+}
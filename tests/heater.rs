@@ -0,0 +1,101 @@
+mod common;
+use crate::common::{destroy, new, BitFlags as BF, Register, BASE_ADDR};
+use embedded_hal::i2c::ErrorKind;
+use embedded_hal_mock::eh1::{delay::NoopDelay as NoDelay, i2c::Transaction as I2cTrans};
+use hdc20xx::{Error, InterruptConfig};
+
+#[test]
+fn can_enable_heater() {
+    let mut sensor = new(&[I2cTrans::write(
+        BASE_ADDR,
+        vec![Register::DEVICE_CONFIG, BF::HEAT_EN],
+    )]);
+    sensor.enable_heater().unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_disable_heater() {
+    let mut sensor = new(&[
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, BF::HEAT_EN]),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, 0]),
+    ]);
+    sensor.enable_heater().unwrap();
+    sensor.disable_heater().unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_clear_condensation() {
+    let mut sensor = new(&[
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, BF::HEAT_EN]),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, 0]),
+    ]);
+    sensor.clear_condensation(&mut NoDelay, 1000).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn clear_condensation_restores_previous_config() {
+    let mut sensor = new(&[
+        I2cTrans::write(BASE_ADDR, vec![Register::INTERRUPT_ENABLE, BF::DRDY_ENABLE]),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, BF::INT_EN]),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::DEVICE_CONFIG, BF::INT_EN | BF::HEAT_EN],
+        ),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, BF::INT_EN]),
+    ]);
+    sensor
+        .set_interrupt_config(InterruptConfig {
+            data_ready: true,
+            ..Default::default()
+        })
+        .unwrap();
+    sensor.clear_condensation(&mut NoDelay, 10_000).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn clear_condensation_retries_disabling_heater() {
+    let mut sensor = new(&[
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, BF::HEAT_EN]),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, 0]).with_error(ErrorKind::Other),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, 0]),
+    ]);
+    sensor.clear_condensation(&mut NoDelay, 1000).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn clear_condensation_reports_failure_to_disable_heater() {
+    let mut sensor = new(&[
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, BF::HEAT_EN]),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, 0]).with_error(ErrorKind::Other),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, 0]).with_error(ErrorKind::Other),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, 0]),
+    ]);
+    match sensor.clear_condensation(&mut NoDelay, 1000) {
+        Err(Error::I2C(ErrorKind::Other)) => (),
+        _ => panic!("I2C error not returned."),
+    }
+    sensor.disable_heater().unwrap();
+    destroy(sensor);
+}
+
+macro_rules! invalid_duration_test {
+    ($name:ident, $duration:expr) => {
+        #[test]
+        fn $name() {
+            let mut sensor = new(&[]);
+            match sensor.clear_condensation(&mut NoDelay, $duration) {
+                Err(Error::InvalidInputData) => (),
+                _ => panic!("InvalidInputData not returned."),
+            }
+            destroy(sensor);
+        }
+    };
+}
+
+invalid_duration_test!(cannot_clear_condensation_for_0ms, 0);
+invalid_duration_test!(cannot_clear_condensation_too_long, 10_001);
@@ -1,7 +1,11 @@
 mod common;
 use crate::common::{destroy, new, BitFlags as BF, Register, BASE_ADDR};
-use embedded_hal_mock::i2c::Transaction as I2cTrans;
-use hdc20xx::MeasurementMode;
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+use embedded_hal_mock::eh1::{
+    delay::{CheckedDelay, NoopDelay, Transaction as DelayTrans},
+    i2c::Mock as I2cMock,
+};
+use hdc20xx::{Error, Hdc20xx, MeasurementMode, Resolution, SlaveAddr, Variant};
 
 #[test]
 fn can_create_and_destroy() {
@@ -46,7 +50,6 @@ macro_rules! set_test {
     };
 }
 
-set_test!(sw_reset_one_shot, software_reset, MEAS_CONF, BF::SOFT_RESET);
 set_test!(
     set_temp_and_humidity_mode,
     set_measurement_mode,
@@ -113,3 +116,300 @@ fn can_make_one_shot_measurement_temp_only() {
     assert!(data.status.data_ready);
     destroy(sensor);
 }
+
+set_test!(
+    set_temp_res_14,
+    set_temperature_resolution,
+    MEAS_CONF,
+    0,
+    Resolution::Bits14
+);
+set_test!(
+    set_temp_res_11,
+    set_temperature_resolution,
+    MEAS_CONF,
+    BF::TRES_11,
+    Resolution::Bits11
+);
+set_test!(
+    set_temp_res_9,
+    set_temperature_resolution,
+    MEAS_CONF,
+    BF::TRES_9,
+    Resolution::Bits9
+);
+set_test!(
+    set_rh_res_14,
+    set_humidity_resolution,
+    MEAS_CONF,
+    0,
+    Resolution::Bits14
+);
+set_test!(
+    set_rh_res_11,
+    set_humidity_resolution,
+    MEAS_CONF,
+    BF::HRES_11,
+    Resolution::Bits11
+);
+set_test!(
+    set_rh_res_9,
+    set_humidity_resolution,
+    MEAS_CONF,
+    BF::HRES_9,
+    Resolution::Bits9
+);
+
+#[test]
+fn resolution_is_kept_when_triggering_measurement() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::TRES_11]),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::MEAS_CONF, BF::TRES_11 | BF::HRES_9],
+        ),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![
+                Register::MEAS_CONF,
+                BF::TRES_11 | BF::HRES_9 | BF::MEAS_TRIG,
+            ],
+        ),
+    ];
+    let mut sensor = new(&transactions);
+    sensor
+        .set_temperature_resolution(Resolution::Bits11)
+        .unwrap();
+    sensor.set_humidity_resolution(Resolution::Bits9).unwrap();
+    sensor.read().expect_err("should block");
+    destroy(sensor);
+}
+
+#[test]
+fn can_get_default_conversion_time() {
+    let sensor = new(&[]);
+    assert_eq!(1270, sensor.conversion_time_us());
+    destroy(sensor);
+}
+
+#[test]
+fn can_get_conversion_time_with_resolution() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::TRES_11]),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::MEAS_CONF, BF::TRES_11 | BF::HRES_9],
+        ),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![
+                Register::MEAS_CONF,
+                BF::TRES_11 | BF::HRES_9 | BF::TEMP_ONLY,
+            ],
+        ),
+    ];
+    let mut sensor = new(&transactions);
+    sensor
+        .set_temperature_resolution(Resolution::Bits11)
+        .unwrap();
+    assert_eq!(350 + 660, sensor.conversion_time_us());
+    sensor.set_humidity_resolution(Resolution::Bits9).unwrap();
+    assert_eq!(350 + 275, sensor.conversion_time_us());
+    sensor
+        .set_measurement_mode(MeasurementMode::TemperatureOnly)
+        .unwrap();
+    assert_eq!(350, sensor.conversion_time_us());
+    destroy(sensor);
+}
+
+#[test]
+fn can_do_software_reset() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, BF::SOFT_RESET]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::DEVICE_CONFIG],
+            vec![BF::SOFT_RESET],
+        ),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DEVICE_CONFIG], vec![0]),
+    ];
+    let mut sensor = new(&transactions);
+    let mut delay = CheckedDelay::new(&[
+        DelayTrans::blocking_delay_us(500),
+        DelayTrans::blocking_delay_us(500),
+    ]);
+    sensor.software_reset(&mut delay).unwrap();
+    destroy(sensor);
+    delay.done();
+}
+
+#[test]
+fn software_reset_times_out() {
+    let mut transactions = vec![I2cTrans::write(
+        BASE_ADDR,
+        vec![Register::DEVICE_CONFIG, BF::SOFT_RESET],
+    )];
+    transactions.extend(
+        (0..10).map(|_| I2cTrans::write_read(BASE_ADDR, vec![Register::DEVICE_CONFIG], vec![0xFF])),
+    );
+    let mut sensor = new(&transactions);
+    match sensor.software_reset(&mut NoopDelay::new()) {
+        Err(Error::Timeout) => (),
+        _ => panic!("expected timeout"),
+    }
+    destroy(sensor);
+}
+
+#[test]
+fn software_reset_restores_default_configuration() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::TEMP_ONLY]),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::MEAS_CONF, BF::TEMP_ONLY | BF::TRES_9],
+        ),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, BF::HEAT_EN]),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![
+                Register::MEAS_CONF,
+                BF::TEMP_ONLY | BF::TRES_9 | BF::MEAS_TRIG,
+            ],
+        ),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, BF::SOFT_RESET]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DEVICE_CONFIG], vec![0]),
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, BF::HEAT_EN]),
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+    ];
+    let mut sensor = new(&transactions);
+    sensor
+        .set_measurement_mode(MeasurementMode::TemperatureOnly)
+        .unwrap();
+    sensor
+        .set_temperature_resolution(Resolution::Bits9)
+        .unwrap();
+    sensor.enable_heater().unwrap();
+    sensor.read().expect_err("should block");
+    sensor.software_reset(&mut NoopDelay::new()).unwrap();
+    assert_eq!(1270, sensor.conversion_time_us());
+    sensor.enable_heater().unwrap();
+    // a new measurement is triggered instead of polling the status
+    sensor.read().expect_err("should block");
+    destroy(sensor);
+}
+
+#[test]
+fn can_create_checked() {
+    let transactions = [
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::MANUFACTURER_ID_L],
+            vec![0x49, 0x54],
+        ),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DEVICE_ID_L], vec![0xD0, 0x07]),
+    ];
+    let sensor = Hdc20xx::new_checked(
+        I2cMock::new(&transactions),
+        SlaveAddr::default(),
+        Variant::Hdc2080,
+    )
+    .unwrap();
+    assert_eq!(Some(Variant::Hdc2080), sensor.variant());
+    destroy(sensor);
+}
+
+#[test]
+fn unchecked_has_no_variant() {
+    let sensor = new(&[]);
+    assert_eq!(None, sensor.variant());
+    destroy(sensor);
+}
+
+macro_rules! wrong_id_test {
+    ($name:ident, $manuf:expr, $dev:expr) => {
+        #[test]
+        fn $name() {
+            let transactions = [
+                I2cTrans::write_read(
+                    BASE_ADDR,
+                    vec![Register::MANUFACTURER_ID_L],
+                    vec![$manuf as u8, ($manuf >> 8) as u8],
+                ),
+                I2cTrans::write_read(
+                    BASE_ADDR,
+                    vec![Register::DEVICE_ID_L],
+                    vec![$dev as u8, ($dev >> 8) as u8],
+                ),
+            ];
+            let mut i2c = I2cMock::new(&transactions);
+            match Hdc20xx::new_checked(i2c.clone(), SlaveAddr::default(), Variant::Hdc2010) {
+                Err(Error::UnexpectedDeviceId {
+                    manufacturer,
+                    device,
+                }) => {
+                    assert_eq!($manuf, manufacturer);
+                    assert_eq!($dev, device);
+                }
+                _ => panic!("UnexpectedDeviceId not returned."),
+            }
+            i2c.done();
+        }
+    };
+}
+
+wrong_id_test!(wrong_manufacturer_id, 0x1234_u16, 0x07D0_u16);
+wrong_id_test!(wrong_device_id, 0x5449_u16, 0x1234_u16);
+
+#[test]
+fn can_measure() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![0]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91],
+        ),
+    ];
+    let mut sensor = new(&transactions);
+    let mut delay = CheckedDelay::new(&[
+        DelayTrans::blocking_delay_us(1270),
+        DelayTrans::blocking_delay_us(1270),
+    ]);
+    let data = sensor.measure(&mut delay).unwrap();
+    assert!(data.temperature < 25.5);
+    assert!(data.temperature > 24.5);
+    let rh = data.humidity.unwrap();
+    assert!(rh < 57.5);
+    assert!(rh > 56.5);
+    destroy(sensor);
+    delay.done();
+}
+
+#[test]
+fn measure_times_out() {
+    let mut transactions = vec![I2cTrans::write(
+        BASE_ADDR,
+        vec![Register::MEAS_CONF, BF::MEAS_TRIG],
+    )];
+    for _ in 0..10 {
+        transactions.push(I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::DRDY],
+            vec![0],
+        ));
+    }
+    transactions.push(I2cTrans::write(
+        BASE_ADDR,
+        vec![Register::MEAS_CONF, BF::MEAS_TRIG],
+    ));
+    let mut sensor = new(&transactions);
+    match sensor.measure(&mut NoopDelay::new()) {
+        Err(Error::Timeout) => (),
+        _ => panic!("Timeout not returned."),
+    }
+    // a new measurement is triggered afterwards
+    sensor.read().expect_err("should block");
+    destroy(sensor);
+}
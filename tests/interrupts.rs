@@ -0,0 +1,120 @@
+mod common;
+use crate::common::{destroy, new, BitFlags as BF, Register, BASE_ADDR};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+use hdc20xx::{InterruptConfig, InterruptMode, InterruptPolarity, SamplingRate};
+
+macro_rules! int_test {
+    ($name:ident, $int_enable:expr, $dev_config:expr, $config:expr) => {
+        #[test]
+        fn $name() {
+            let mut sensor = new(&[
+                I2cTrans::write(BASE_ADDR, vec![Register::INTERRUPT_ENABLE, $int_enable]),
+                I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, $dev_config]),
+            ]);
+            sensor.set_interrupt_config($config).unwrap();
+            destroy(sensor);
+        }
+    };
+}
+
+int_test!(all_disabled, 0, 0, InterruptConfig::default());
+int_test!(
+    drdy,
+    BF::DRDY_ENABLE,
+    BF::INT_EN,
+    InterruptConfig {
+        data_ready: true,
+        ..Default::default()
+    }
+);
+int_test!(
+    high_temp,
+    BF::TH_ENABLE,
+    BF::INT_EN,
+    InterruptConfig {
+        high_temperature: true,
+        ..Default::default()
+    }
+);
+int_test!(
+    low_temp,
+    BF::TL_ENABLE,
+    BF::INT_EN,
+    InterruptConfig {
+        low_temperature: true,
+        ..Default::default()
+    }
+);
+int_test!(
+    high_rh,
+    BF::HH_ENABLE,
+    BF::INT_EN,
+    InterruptConfig {
+        high_humidity: true,
+        ..Default::default()
+    }
+);
+int_test!(
+    low_rh,
+    BF::HL_ENABLE,
+    BF::INT_EN,
+    InterruptConfig {
+        low_humidity: true,
+        ..Default::default()
+    }
+);
+int_test!(
+    active_high,
+    0,
+    BF::INT_POL,
+    InterruptConfig {
+        polarity: InterruptPolarity::ActiveHigh,
+        ..Default::default()
+    }
+);
+int_test!(
+    comparator,
+    0,
+    BF::INT_MODE,
+    InterruptConfig {
+        mode: InterruptMode::Comparator,
+        ..Default::default()
+    }
+);
+int_test!(
+    all_enabled,
+    BF::DRDY_ENABLE | BF::TH_ENABLE | BF::TL_ENABLE | BF::HH_ENABLE | BF::HL_ENABLE,
+    BF::INT_EN | BF::INT_POL | BF::INT_MODE,
+    InterruptConfig {
+        data_ready: true,
+        high_temperature: true,
+        low_temperature: true,
+        high_humidity: true,
+        low_humidity: true,
+        polarity: InterruptPolarity::ActiveHigh,
+        mode: InterruptMode::Comparator,
+    }
+);
+
+#[test]
+fn interrupt_config_keeps_sampling_rate() {
+    let mut sensor = new(&[
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, 5 << 4]),
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write(BASE_ADDR, vec![Register::INTERRUPT_ENABLE, BF::DRDY_ENABLE]),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::DEVICE_CONFIG, 5 << 4 | BF::INT_EN],
+        ),
+    ])
+    .into_continuous(SamplingRate::Hz1)
+    .ok()
+    .unwrap();
+    sensor
+        .set_interrupt_config(InterruptConfig {
+            data_ready: true,
+            ..Default::default()
+        })
+        .unwrap();
+    destroy(sensor);
+}
@@ -0,0 +1,102 @@
+mod common;
+use crate::common::{destroy, new, Register, BASE_ADDR};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+use hdc20xx::Error;
+
+macro_rules! set_offset_test {
+    ($name:ident, $method:ident, $reg:ident, $value:expr, $arg:expr) => {
+        #[test]
+        fn $name() {
+            let mut sensor = new(&[I2cTrans::write(BASE_ADDR, vec![Register::$reg, $value])]);
+            sensor.$method($arg).unwrap();
+            destroy(sensor);
+        }
+    };
+}
+
+set_offset_test!(temp_0, set_temperature_offset, TEMP_OFFSET_ADJUST, 0, 0.0);
+set_offset_test!(
+    temp_min,
+    set_temperature_offset,
+    TEMP_OFFSET_ADJUST,
+    0x80,
+    -20.62
+);
+set_offset_test!(
+    temp_max,
+    set_temperature_offset,
+    TEMP_OFFSET_ADJUST,
+    0x7F,
+    20.46
+);
+set_offset_test!(
+    temp_pos,
+    set_temperature_offset,
+    TEMP_OFFSET_ADJUST,
+    0x0A,
+    1.6
+);
+set_offset_test!(
+    temp_neg,
+    set_temperature_offset,
+    TEMP_OFFSET_ADJUST,
+    0xF6,
+    -1.6
+);
+set_offset_test!(rh_0, set_humidity_offset, HUM_OFFSET_ADJUST, 0, 0.0);
+set_offset_test!(rh_min, set_humidity_offset, HUM_OFFSET_ADJUST, 0x80, -25.0);
+set_offset_test!(rh_max, set_humidity_offset, HUM_OFFSET_ADJUST, 0x7F, 24.8);
+set_offset_test!(rh_pos, set_humidity_offset, HUM_OFFSET_ADJUST, 0x0A, 2.0);
+set_offset_test!(rh_neg, set_humidity_offset, HUM_OFFSET_ADJUST, 0xF6, -2.0);
+
+macro_rules! invalid_offset_test {
+    ($name:ident, $method:ident, $arg:expr) => {
+        #[test]
+        fn $name() {
+            let mut sensor = new(&[]);
+            match sensor.$method($arg) {
+                Err(Error::InvalidInputData) => (),
+                _ => panic!("InvalidInputData not returned."),
+            }
+            destroy(sensor);
+        }
+    };
+}
+
+invalid_offset_test!(cannot_set_temp_too_low, set_temperature_offset, -21.0);
+invalid_offset_test!(cannot_set_temp_too_high, set_temperature_offset, 20.6);
+invalid_offset_test!(cannot_set_rh_too_low, set_humidity_offset, -25.2);
+invalid_offset_test!(cannot_set_rh_too_high, set_humidity_offset, 25.0);
+
+macro_rules! get_offset_test {
+    ($name:ident, $method:ident, $reg:ident, $value:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let mut sensor = new(&[I2cTrans::write_read(
+                BASE_ADDR,
+                vec![Register::$reg],
+                vec![$value],
+            )]);
+            let value = sensor.$method().unwrap();
+            assert!((value - $expected).abs() < 0.01);
+            destroy(sensor);
+        }
+    };
+}
+
+get_offset_test!(
+    get_temp_pos,
+    temperature_offset,
+    TEMP_OFFSET_ADJUST,
+    0x01,
+    0.16
+);
+get_offset_test!(
+    get_temp_neg,
+    temperature_offset,
+    TEMP_OFFSET_ADJUST,
+    0x80,
+    -20.62
+);
+get_offset_test!(get_rh_pos, humidity_offset, HUM_OFFSET_ADJUST, 0x40, 12.5);
+get_offset_test!(get_rh_neg, humidity_offset, HUM_OFFSET_ADJUST, 0xFF, -0.2);
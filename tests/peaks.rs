@@ -0,0 +1,112 @@
+mod common;
+use crate::common::{destroy, new, BitFlags as BF, Register, BASE_ADDR};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+use hdc20xx::MeasurementMode;
+
+#[test]
+fn can_read_max_temperature() {
+    let mut sensor = new(&[I2cTrans::write_read(
+        BASE_ADDR,
+        vec![Register::TEMP_MAX],
+        vec![128],
+    )]);
+    let value = sensor.max_temperature().unwrap();
+    assert!((value - 42.5).abs() < 0.01);
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_max_humidity() {
+    let mut sensor = new(&[I2cTrans::write_read(
+        BASE_ADDR,
+        vec![Register::HUM_MAX],
+        vec![192],
+    )]);
+    let value = sensor.max_humidity().unwrap();
+    assert!((value - 75.0).abs() < 0.01);
+    destroy(sensor);
+}
+
+#[test]
+fn peaks_not_included_by_default() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91],
+        ),
+    ];
+    let mut sensor = new(&transactions);
+    sensor.read().expect_err("should block");
+    let data = sensor.read().unwrap();
+    assert!(data.max_temperature.is_none());
+    assert!(data.max_humidity.is_none());
+    destroy(sensor);
+}
+
+#[test]
+fn can_include_peaks_in_measurement() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91],
+        ),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::TEMP_MAX], vec![128, 192]),
+    ];
+    let mut sensor = new(&transactions);
+    sensor.enable_peak_readout();
+    sensor.read().expect_err("should block");
+    let data = sensor.read().unwrap();
+    assert!((data.max_temperature.unwrap() - 42.5).abs() < 0.01);
+    assert!((data.max_humidity.unwrap() - 75.0).abs() < 0.01);
+    destroy(sensor);
+}
+
+#[test]
+fn can_include_peaks_in_measurement_temp_only() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::TEMP_ONLY]),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::MEAS_CONF, BF::TEMP_ONLY | BF::MEAS_TRIG],
+        ),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::TEMP_L], vec![0xD9, 0x64]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::TEMP_MAX], vec![128]),
+    ];
+    let mut sensor = new(&transactions);
+    sensor
+        .set_measurement_mode(MeasurementMode::TemperatureOnly)
+        .unwrap();
+    sensor.enable_peak_readout();
+    sensor.read().expect_err("should block");
+    let data = sensor.read().unwrap();
+    assert!((data.max_temperature.unwrap() - 42.5).abs() < 0.01);
+    assert!(data.max_humidity.is_none());
+    destroy(sensor);
+}
+
+#[test]
+fn can_disable_peak_readout() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91],
+        ),
+    ];
+    let mut sensor = new(&transactions);
+    sensor.enable_peak_readout();
+    sensor.disable_peak_readout();
+    sensor.read().expect_err("should block");
+    let data = sensor.read().unwrap();
+    assert!(data.max_temperature.is_none());
+    destroy(sensor);
+}
@@ -0,0 +1,28 @@
+mod common;
+use crate::common::measurement;
+use hdc20xx::psychrometrics::STANDARD_PRESSURE_HPA;
+
+#[test]
+fn derived_quantities_are_none_without_humidity() {
+    let data = measurement(20.0, None);
+    assert!(data.dew_point().is_none());
+    assert!(data.frost_point().is_none());
+    assert!(data.absolute_humidity().is_none());
+    assert!(data.mixing_ratio(STANDARD_PRESSURE_HPA).is_none());
+    assert!(data.heat_index().is_none());
+}
+
+#[test]
+fn can_calculate_derived_quantities() {
+    let data = measurement(20.0, Some(50.0));
+    let dew_point = data.dew_point().unwrap();
+    assert!(dew_point > 9.0 && dew_point < 9.5);
+    let frost_point = data.frost_point().unwrap();
+    assert!(frost_point < 20.0);
+    let absolute = data.absolute_humidity().unwrap();
+    assert!(absolute > 8.5 && absolute < 8.8);
+    let mixing_ratio = data.mixing_ratio(STANDARD_PRESSURE_HPA).unwrap();
+    assert!(mixing_ratio > 7.2 && mixing_ratio < 7.4);
+    let heat_index = data.heat_index().unwrap();
+    assert!(heat_index > 19.0 && heat_index < 21.0);
+}
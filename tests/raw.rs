@@ -0,0 +1,152 @@
+mod common;
+use crate::common::{destroy, new, BitFlags as BF, Register, BASE_ADDR};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+use hdc20xx::{Measurement, MeasurementMode, RawMeasurement, SamplingRate, Status};
+
+const STATUS_READY: Status = Status {
+    data_ready: true,
+    high_temp_threshold_exceeded: false,
+    low_temp_threshold_exceeded: false,
+    high_humidity_threshold_exceeded: false,
+    low_humidity_threshold_exceeded: false,
+};
+
+#[test]
+fn can_make_raw_one_shot_measurement_temp_and_humidity() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![0]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91],
+        ),
+    ];
+    let mut sensor = new(&transactions);
+    sensor.read_raw().expect_err("should block");
+    sensor.read_raw().expect_err("should block");
+    let data = sensor.read_raw().unwrap();
+    assert_eq!(
+        RawMeasurement {
+            temperature: 0x64D9,
+            humidity: Some(0x91EC),
+            status: STATUS_READY,
+        },
+        data
+    );
+    destroy(sensor);
+}
+
+#[test]
+fn can_make_raw_one_shot_measurement_temp_only() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::TEMP_ONLY]),
+        I2cTrans::write(
+            BASE_ADDR,
+            vec![Register::MEAS_CONF, BF::TEMP_ONLY | BF::MEAS_TRIG],
+        ),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::TEMP_L], vec![0xD9, 0x64]),
+    ];
+    let mut sensor = new(&transactions);
+    sensor
+        .set_measurement_mode(MeasurementMode::TemperatureOnly)
+        .unwrap();
+    sensor.read_raw().expect_err("should block");
+    let data = sensor.read_raw().unwrap();
+    assert_eq!(0x64D9, data.temperature);
+    assert!(data.humidity.is_none());
+    destroy(sensor);
+}
+
+#[test]
+fn raw_measurement_does_not_read_peaks() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91],
+        ),
+    ];
+    let mut sensor = new(&transactions);
+    sensor.enable_peak_readout();
+    sensor.read_raw().expect_err("should block");
+    let data = sensor.read_raw().unwrap();
+    assert_eq!(Some(0x91EC), data.humidity);
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_raw_in_continuous_mode() {
+    let transactions = [
+        I2cTrans::write(BASE_ADDR, vec![Register::DEVICE_CONFIG, 5 << 4]),
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![0]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91],
+        ),
+    ];
+    let sensor = new(&transactions);
+    let mut sensor = sensor.into_continuous(SamplingRate::Hz1).ok().unwrap();
+    let data = sensor.read_raw().unwrap();
+    assert_eq!(0x64D9, data.temperature);
+    assert_eq!(Some(0x91EC), data.humidity);
+    assert!(!data.status.data_ready);
+    destroy(sensor);
+}
+
+#[test]
+fn can_convert_raw_measurement() {
+    let raw = RawMeasurement {
+        temperature: 0x64D9,
+        humidity: Some(0x91EC),
+        status: STATUS_READY,
+    };
+    let data = Measurement::from(raw);
+    assert!(data.temperature < 25.5);
+    assert!(data.temperature > 24.5);
+    let rh = data.humidity.unwrap();
+    assert!(rh < 57.5);
+    assert!(rh > 56.5);
+    assert!(data.max_temperature.is_none());
+    assert!(data.max_humidity.is_none());
+    assert_eq!(STATUS_READY, data.status);
+}
+
+#[test]
+fn can_convert_raw_measurement_temp_only() {
+    let raw = RawMeasurement {
+        temperature: 0,
+        humidity: None,
+        status: STATUS_READY,
+    };
+    let data: Measurement = raw.into();
+    assert_eq!(-40.0, data.temperature);
+    assert!(data.humidity.is_none());
+}
+
+#[test]
+fn can_convert_raw_measurement_to_fixed_point() {
+    let raw = RawMeasurement {
+        temperature: 0x64D9,
+        humidity: Some(0x91EC),
+        status: STATUS_READY,
+    };
+    assert_eq!(24_999, raw.temperature_milli_celsius());
+    assert_eq!(Some(57_001), raw.humidity_milli_percent());
+}
+
+#[test]
+fn fixed_point_humidity_is_none_for_temp_only() {
+    let raw = RawMeasurement {
+        temperature: 0x64D9,
+        humidity: None,
+        status: STATUS_READY,
+    };
+    assert_eq!(None, raw.humidity_milli_percent());
+}
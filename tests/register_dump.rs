@@ -0,0 +1,132 @@
+mod common;
+use crate::common::{destroy, new, BitFlags as BF, Register, BASE_ADDR};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+use hdc20xx::{DeviceConfiguration, RegisterDump};
+
+const REGISTERS: [u8; 16] = [
+    0xD9,
+    0x64,
+    0xEC,
+    0x91,
+    BF::DRDY_STATUS | BF::TH_STATUS,
+    0x66,
+    0x99,
+    BF::DRDY_ENABLE | BF::HL_ENABLE,
+    0x06,
+    0xF6,
+    0x01,
+    0xFF,
+    0x00,
+    0xFF,
+    (5 << 4) | BF::HEAT_EN | BF::INT_EN | BF::INT_POL,
+    (1 << 6) | (2 << 4) | BF::TEMP_ONLY,
+];
+
+fn dump_transactions() -> [I2cTrans; 2] {
+    [
+        I2cTrans::write_read(BASE_ADDR, vec![Register::TEMP_L], REGISTERS.to_vec()),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::MANUFACTURER_ID_L],
+            vec![0x49, 0x54, 0xD0, 0x07],
+        ),
+    ]
+}
+
+#[test]
+fn can_dump_registers() {
+    let mut sensor = new(&dump_transactions());
+    let dump = sensor.dump_registers().unwrap();
+    assert_eq!(
+        RegisterDump {
+            registers: REGISTERS,
+            manufacturer_id: 0x5449,
+            device_id: 0x07D0,
+        },
+        dump
+    );
+    destroy(sensor);
+}
+
+#[test]
+fn dump_contains_configuration() {
+    let mut sensor = new(&dump_transactions());
+    let dump = sensor.dump_registers().unwrap();
+    assert_eq!(
+        DeviceConfiguration {
+            temperature_max: Some(0x66),
+            humidity_max: Some(0x99),
+            interrupt_enable: BF::DRDY_ENABLE | BF::HL_ENABLE,
+            temperature_offset: 0x06,
+            humidity_offset: 0xF6,
+            temperature_threshold_low: 0x01,
+            temperature_threshold_high: 0xFF,
+            humidity_threshold_low: 0x00,
+            humidity_threshold_high: 0xFF,
+            device_config: REGISTERS[14],
+            measurement_config: REGISTERS[15],
+        },
+        dump.configuration()
+    );
+    destroy(sensor);
+}
+
+#[test]
+fn names_all_registers() {
+    let mut sensor = new(&dump_transactions());
+    let registers = sensor.dump_registers().unwrap().named_registers();
+    assert_eq!((0x00, "TEMPERATURE_LOW", 0xD9), registers[0]);
+    assert_eq!((0x0F, "MEASUREMENT_CONFIG", REGISTERS[15]), registers[15]);
+    assert_eq!((0xFC, "MANUFACTURER_ID_LOW", 0x49), registers[16]);
+    assert_eq!((0xFF, "DEVICE_ID_HIGH", 0x07), registers[19]);
+    destroy(sensor);
+}
+
+#[test]
+fn can_display_decoded_registers() {
+    let mut sensor = new(&dump_transactions());
+    let text = sensor.dump_registers().unwrap().to_string();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(20, lines.len());
+    assert_eq!("0x00 TEMPERATURE_LOW      0xD9", lines[0]);
+    assert_eq!(
+        "0x01 TEMPERATURE_HIGH     0x64  temperature 25.00°C",
+        lines[1]
+    );
+    assert_eq!(
+        "0x03 HUMIDITY_HIGH        0x91  humidity 57.00%RH",
+        lines[3]
+    );
+    assert_eq!(
+        "0x04 INTERRUPT_DRDY       0xC0  DRDY_STATUS=1 TH_STATUS=1 TL_STATUS=0 HH_STATUS=0 HL_STATUS=0",
+        lines[4]
+    );
+    assert_eq!("0x05 TEMPERATURE_MAX      0x66  25.74°C", lines[5]);
+    assert_eq!("0x06 HUMIDITY_MAX         0x99  59.77%RH", lines[6]);
+    assert_eq!(
+        "0x07 INTERRUPT_ENABLE     0x88  DRDY_ENABLE=1 TH_ENABLE=0 TL_ENABLE=0 HH_ENABLE=0 HL_ENABLE=1",
+        lines[7]
+    );
+    assert_eq!("0x08 TEMP_OFFSET_ADJUST   0x06  +0.97°C", lines[8]);
+    assert_eq!("0x09 HUM_OFFSET_ADJUST    0xF6  -1.95%RH", lines[9]);
+    assert_eq!("0x0A TEMP_THR_L           0x01  -39.36°C", lines[10]);
+    assert_eq!("0x0D RH_THR_H             0xFF  99.61%RH", lines[13]);
+    assert_eq!(
+        "0x0E DEVICE_CONFIG        0x5E  SOFT_RES=0 AMM=1 Hz HEAT_EN=1 INT_EN=1 INT_POL=active high INT_MODE=level sensitive",
+        lines[14]
+    );
+    assert_eq!(
+        "0x0F MEASUREMENT_CONFIG   0x62  TRES=11 bits HRES=9 bits MEAS_CONF=temperature only MEAS_TRIG=0",
+        lines[15]
+    );
+    assert_eq!("0xFC MANUFACTURER_ID_LOW  0x49", lines[16]);
+    assert_eq!(
+        "0xFD MANUFACTURER_ID_HIGH 0x54  manufacturer ID 0x5449",
+        lines[17]
+    );
+    assert_eq!(
+        "0xFF DEVICE_ID_HIGH       0x07  device ID 0x07D0",
+        lines[19]
+    );
+    destroy(sensor);
+}
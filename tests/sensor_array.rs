@@ -0,0 +1,174 @@
+mod common;
+use crate::common::{BitFlags as BF, Register, BASE_ADDR};
+use core::cell::RefCell;
+use embedded_hal::i2c::ErrorKind;
+use embedded_hal_bus::i2c::RefCellDevice;
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+use hdc20xx::{
+    ChannelSelector, Error, MeasurementMode, SensorArray, SensorArrayError, SlaveAddr, Tca9548a,
+    Tca9548aError,
+};
+
+const MUX_ADDR: u8 = 0x70;
+const ALT_ADDR: u8 = BASE_ADDR | 1;
+
+fn select(channel: u8) -> I2cTrans {
+    I2cTrans::write(MUX_ADDR, vec![1 << channel])
+}
+
+fn trigger(address: u8) -> I2cTrans {
+    I2cTrans::write(address, vec![Register::MEAS_CONF, BF::MEAS_TRIG])
+}
+
+fn status(address: u8, status: u8) -> I2cTrans {
+    I2cTrans::write_read(address, vec![Register::DRDY], vec![status])
+}
+
+fn data(address: u8) -> I2cTrans {
+    I2cTrans::write_read(
+        address,
+        vec![Register::TEMP_L],
+        vec![0xD9, 0x64, 0xEC, 0x91],
+    )
+}
+
+const LOCATIONS: [(u8, SlaveAddr); 3] = [
+    (0, SlaveAddr::Default),
+    (0, SlaveAddr::Alternative(true)),
+    (3, SlaveAddr::Default),
+];
+
+#[test]
+fn can_measure_all() {
+    let transactions = [
+        select(0),
+        trigger(BASE_ADDR),
+        select(0),
+        trigger(ALT_ADDR),
+        select(3),
+        trigger(BASE_ADDR),
+        select(0),
+        status(BASE_ADDR, BF::DRDY_STATUS),
+        data(BASE_ADDR),
+        select(0),
+        status(ALT_ADDR, 0),
+        status(ALT_ADDR, BF::DRDY_STATUS),
+        data(ALT_ADDR),
+        select(3),
+        status(BASE_ADDR, BF::DRDY_STATUS),
+        data(BASE_ADDR),
+    ];
+    let bus = RefCell::new(I2cMock::new(&transactions));
+    let mux = Tca9548a::new(RefCellDevice::new(&bus), MUX_ADDR);
+    let mut array = SensorArray::new(mux, LOCATIONS, || RefCellDevice::new(&bus));
+    assert_eq!(LOCATIONS, array.locations());
+    let results = array.measure_all(&mut NoopDelay::new());
+    for (result, (channel, address)) in results.iter().zip(LOCATIONS.iter()) {
+        assert_eq!(*channel, result.channel);
+        assert_eq!(*address, result.address);
+        let data = result.result.as_ref().unwrap();
+        assert!(data.temperature < 25.5);
+        assert!(data.temperature > 24.5);
+    }
+    array.destroy();
+    bus.into_inner().done();
+}
+
+#[test]
+fn reports_errors_per_sensor() {
+    let transactions = [
+        select(0),
+        trigger(BASE_ADDR),
+        select(0),
+        trigger(ALT_ADDR).with_error(ErrorKind::Other),
+        select(3).with_error(ErrorKind::Other),
+        select(0),
+        status(BASE_ADDR, BF::DRDY_STATUS),
+        data(BASE_ADDR),
+    ];
+    let bus = RefCell::new(I2cMock::new(&transactions));
+    let mux = Tca9548a::new(RefCellDevice::new(&bus), MUX_ADDR);
+    let mut array = SensorArray::new(mux, LOCATIONS, || RefCellDevice::new(&bus));
+    let [first, second, third] = array.measure_all(&mut NoopDelay::new());
+    assert!(first.result.is_ok());
+    match second.result {
+        Err(SensorArrayError::Sensor(Error::I2C(_))) => (),
+        _ => panic!("expected sensor error"),
+    }
+    match third.result {
+        Err(SensorArrayError::Selector(Tca9548aError::I2C(_))) => (),
+        _ => panic!("expected selector error"),
+    }
+    array.destroy();
+    bus.into_inner().done();
+}
+
+#[test]
+fn reports_timeout_per_sensor() {
+    let mut transactions = vec![select(0), trigger(BASE_ADDR), select(0)];
+    transactions.extend((0..10).map(|_| status(BASE_ADDR, 0)));
+    let bus = RefCell::new(I2cMock::new(&transactions));
+    let mux = Tca9548a::new(RefCellDevice::new(&bus), MUX_ADDR);
+    let locations = [(0, SlaveAddr::default())];
+    let mut array = SensorArray::new(mux, locations, || RefCellDevice::new(&bus));
+    let [result] = array.measure_all(&mut NoopDelay::new());
+    match result.result {
+        Err(SensorArrayError::Sensor(Error::Timeout)) => (),
+        _ => panic!("expected timeout"),
+    }
+    array.destroy();
+    bus.into_inner().done();
+}
+
+#[test]
+fn can_select_sensor() {
+    let transactions = [
+        select(3),
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::TEMP_ONLY]),
+    ];
+    let bus = RefCell::new(I2cMock::new(&transactions));
+    let mux = Tca9548a::new(RefCellDevice::new(&bus), MUX_ADDR);
+    let mut array = SensorArray::new(mux, LOCATIONS, || RefCellDevice::new(&bus));
+    array
+        .select(3, SlaveAddr::default())
+        .unwrap()
+        .set_measurement_mode(MeasurementMode::TemperatureOnly)
+        .unwrap();
+    array.destroy();
+    bus.into_inner().done();
+}
+
+#[test]
+fn selecting_unknown_sensor_fails() {
+    let bus = RefCell::new(I2cMock::new(&[]));
+    let mux = Tca9548a::new(RefCellDevice::new(&bus), MUX_ADDR);
+    let mut array = SensorArray::new(mux, LOCATIONS, || RefCellDevice::new(&bus));
+    match array.select(1, SlaveAddr::default()) {
+        Err(SensorArrayError::UnknownSensor) => (),
+        _ => panic!("expected unknown sensor"),
+    }
+    array.destroy();
+    bus.into_inner().done();
+}
+
+#[test]
+fn mux_rejects_invalid_channel() {
+    let mut mock = I2cMock::new(&[]);
+    let mut mux = Tca9548a::new(&mut mock, MUX_ADDR);
+    match mux.select(8) {
+        Err(Tca9548aError::InvalidChannel) => (),
+        _ => panic!("expected invalid channel"),
+    }
+    mock.done();
+}
+
+#[test]
+fn mux_can_disable_all_channels() {
+    let mut mock = I2cMock::new(&[I2cTrans::write(MUX_ADDR, vec![0])]);
+    let mut mux = Tca9548a::new(&mut mock, MUX_ADDR);
+    mux.disable_all().unwrap();
+    mock.done();
+}
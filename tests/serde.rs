@@ -0,0 +1,92 @@
+#![cfg(feature = "serde")]
+use hdc20xx::{
+    DeviceConfiguration, InterruptConfig, InterruptMode, InterruptPolarity, Measurement,
+    MeasurementMode, RawMeasurement, RegisterDump, Resolution, SamplingRate, SlaveAddr, Status,
+    Variant,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::fmt::Debug;
+
+fn round_trip<T>(value: T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let bytes = postcard::to_allocvec(&value).unwrap();
+    assert_eq!(value, postcard::from_bytes::<T>(&bytes).unwrap());
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(value, serde_json::from_str::<T>(&json).unwrap());
+}
+
+const STATUS: Status = Status {
+    data_ready: true,
+    high_temp_threshold_exceeded: false,
+    low_temp_threshold_exceeded: true,
+    high_humidity_threshold_exceeded: false,
+    low_humidity_threshold_exceeded: true,
+};
+
+#[test]
+fn can_round_trip_measurement() {
+    round_trip(Measurement {
+        temperature: 24.5,
+        humidity: Some(56.25),
+        max_temperature: Some(30.0),
+        max_humidity: None,
+        status: STATUS,
+    });
+}
+
+#[test]
+fn can_round_trip_raw_measurement() {
+    round_trip(RawMeasurement {
+        temperature: 0x64D9,
+        humidity: None,
+        status: STATUS,
+    });
+}
+
+#[test]
+fn can_round_trip_status() {
+    round_trip(STATUS);
+}
+
+#[test]
+fn can_round_trip_configuration_types() {
+    round_trip(MeasurementMode::TemperatureOnly);
+    round_trip(Resolution::Bits11);
+    round_trip(SamplingRate::OnePer5s);
+    round_trip(SlaveAddr::Alternative(true));
+    round_trip(Variant::Hdc2021);
+    round_trip(InterruptConfig {
+        data_ready: true,
+        high_temperature: true,
+        polarity: InterruptPolarity::ActiveHigh,
+        mode: InterruptMode::Comparator,
+        ..Default::default()
+    });
+    round_trip(DeviceConfiguration {
+        temperature_offset: 0x12,
+        measurement_config: 0x42,
+        ..Default::default()
+    });
+    round_trip(RegisterDump {
+        registers: [0x5A; 16],
+        manufacturer_id: 0x5449,
+        device_id: 0x07D0,
+    });
+}
+
+#[test]
+fn measurement_serializes_to_json_fields() {
+    let data = Measurement {
+        temperature: 25.0,
+        humidity: None,
+        max_temperature: None,
+        max_humidity: None,
+        status: STATUS,
+    };
+    let json = serde_json::to_value(data).unwrap();
+    assert_eq!(25.0, json["temperature"]);
+    assert!(json["humidity"].is_null());
+    assert_eq!(true, json["status"]["data_ready"]);
+}
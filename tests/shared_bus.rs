@@ -0,0 +1,118 @@
+mod common;
+use crate::common::{BitFlags as BF, Register, BASE_ADDR};
+use core::cell::RefCell;
+use embedded_hal::i2c::I2c;
+use embedded_hal_bus::i2c::{CriticalSectionDevice, MutexDevice, RefCellDevice};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+use hdc20xx::{mode, Hdc20xx, MeasurementMode, SamplingRate, SlaveAddr};
+use std::sync::Mutex;
+
+const ALT_ADDR: u8 = BASE_ADDR | 1;
+
+fn interleaved_transactions() -> [I2cTrans; 8] {
+    [
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write(ALT_ADDR, vec![Register::MEAS_CONF, BF::TEMP_ONLY]),
+        I2cTrans::write(
+            ALT_ADDR,
+            vec![Register::MEAS_CONF, BF::TEMP_ONLY | BF::MEAS_TRIG],
+        ),
+        I2cTrans::write_read(ALT_ADDR, vec![Register::DRDY], vec![0]),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91],
+        ),
+        I2cTrans::write_read(ALT_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(ALT_ADDR, vec![Register::TEMP_L], vec![0x00, 0x00]),
+    ]
+}
+
+fn interleave_measurements<I2C: I2c>(
+    first: &mut Hdc20xx<I2C, mode::OneShot>,
+    second: &mut Hdc20xx<I2C, mode::OneShot>,
+) {
+    first.read().expect_err("should block");
+    second
+        .set_measurement_mode(MeasurementMode::TemperatureOnly)
+        .unwrap();
+    second.read().expect_err("should block");
+    second.read().expect_err("should block");
+    let data = first.read().unwrap();
+    assert!(data.temperature < 25.5);
+    assert!(data.temperature > 24.5);
+    assert!(data.humidity.is_some());
+    let data = second.read().unwrap();
+    assert_eq!(-40.0, data.temperature);
+    assert!(data.humidity.is_none());
+}
+
+#[test]
+fn can_share_bus_with_ref_cell() {
+    let bus = RefCell::new(I2cMock::new(&interleaved_transactions()));
+    let mut first = Hdc20xx::new(RefCellDevice::new(&bus), SlaveAddr::default());
+    let mut second = Hdc20xx::new(RefCellDevice::new(&bus), SlaveAddr::Alternative(true));
+    interleave_measurements(&mut first, &mut second);
+    first.destroy();
+    second.destroy();
+    bus.into_inner().done();
+}
+
+#[test]
+fn can_share_bus_with_critical_section() {
+    let bus = critical_section::Mutex::new(RefCell::new(I2cMock::new(&interleaved_transactions())));
+    let mut first = Hdc20xx::new(CriticalSectionDevice::new(&bus), SlaveAddr::default());
+    let mut second = Hdc20xx::new(
+        CriticalSectionDevice::new(&bus),
+        SlaveAddr::Alternative(true),
+    );
+    interleave_measurements(&mut first, &mut second);
+    first.destroy();
+    second.destroy();
+    bus.into_inner().into_inner().done();
+}
+
+#[test]
+fn can_share_bus_with_mutex() {
+    let bus = Mutex::new(I2cMock::new(&interleaved_transactions()));
+    let mut first = Hdc20xx::new(MutexDevice::new(&bus), SlaveAddr::default());
+    let mut second = Hdc20xx::new(MutexDevice::new(&bus), SlaveAddr::Alternative(true));
+    interleave_measurements(&mut first, &mut second);
+    first.destroy();
+    second.destroy();
+    bus.into_inner().unwrap().done();
+}
+
+#[test]
+fn can_share_bus_across_modes() {
+    let transactions = [
+        I2cTrans::write(ALT_ADDR, vec![Register::DEVICE_CONFIG, 5 << 4]),
+        I2cTrans::write(ALT_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write(BASE_ADDR, vec![Register::MEAS_CONF, BF::MEAS_TRIG]),
+        I2cTrans::write_read(ALT_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(
+            ALT_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91],
+        ),
+        I2cTrans::write_read(BASE_ADDR, vec![Register::DRDY], vec![BF::DRDY_STATUS]),
+        I2cTrans::write_read(
+            BASE_ADDR,
+            vec![Register::TEMP_L],
+            vec![0xD9, 0x64, 0xEC, 0x91],
+        ),
+    ];
+    let bus = RefCell::new(I2cMock::new(&transactions));
+    let mut one_shot = Hdc20xx::new(RefCellDevice::new(&bus), SlaveAddr::default());
+    let continuous = Hdc20xx::new(RefCellDevice::new(&bus), SlaveAddr::Alternative(true));
+    let mut continuous = continuous.into_continuous(SamplingRate::Hz1).ok().unwrap();
+    one_shot.read().expect_err("should block");
+    let data = continuous.read().unwrap();
+    assert!(data.status.data_ready);
+    let data = one_shot.read().unwrap();
+    assert!(data.humidity.is_some());
+    one_shot.destroy();
+    continuous.destroy();
+    bus.into_inner().done();
+}
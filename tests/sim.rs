@@ -0,0 +1,334 @@
+#![cfg(feature = "sim")]
+use embedded_hal::i2c::{ErrorKind, I2c, NoAcknowledgeSource};
+use hdc20xx::{
+    sim::Simulator, DeviceConfiguration, Error, Hdc20xx, InterruptConfig, InterruptMode,
+    InterruptPolarity, MeasurementMode, Resolution, SamplingRate, SlaveAddr, Variant,
+};
+
+const DRDY: u8 = 0x04;
+const MEAS_CONF: u8 = 0x0F;
+
+fn assert_near(expected: f32, value: f32, tolerance: f32) {
+    assert!(
+        (expected - value).abs() <= tolerance,
+        "expected {} but got {}",
+        expected,
+        value
+    );
+}
+
+#[test]
+fn can_measure() {
+    let sim = Simulator::new(SlaveAddr::default());
+    sim.set_temperature(23.4);
+    sim.set_humidity(61.0);
+    let mut sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    let data = sensor.measure(&mut sim.delay()).unwrap();
+    assert_near(23.4, data.temperature, 0.01);
+    assert_near(61.0, data.humidity.unwrap(), 0.01);
+    assert!(data.status.data_ready);
+    assert_eq!(u64::from(sensor.conversion_time_us()), sim.now_us());
+}
+
+#[test]
+fn one_shot_read_blocks_until_conversion_completes() {
+    let sim = Simulator::new(SlaveAddr::default());
+    let mut sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    sensor.read().expect_err("should block");
+    assert!(sim.is_converting());
+    assert_eq!(1, sim.register(MEAS_CONF));
+    sim.advance_us(sensor.conversion_time_us() - 1);
+    sensor.read().expect_err("should block");
+    sim.advance_us(1);
+    assert!(!sim.is_converting());
+    assert_eq!(0, sim.register(MEAS_CONF));
+    let data = sensor.read().unwrap();
+    assert_near(25.0, data.temperature, 0.01);
+    assert_eq!(0, sim.register(DRDY));
+}
+
+#[test]
+fn does_not_acknowledge_other_address() {
+    let sim = Simulator::new(SlaveAddr::default());
+    let mut sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::Alternative(true));
+    match sensor.measure(&mut sim.delay()) {
+        Err(Error::I2C(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address))) => (),
+        _ => panic!("expected address NACK"),
+    }
+}
+
+#[test]
+fn can_read_ids() {
+    let sim = Simulator::new(SlaveAddr::Alternative(true));
+    let sensor = Hdc20xx::new_checked(sim.i2c(), SlaveAddr::Alternative(true), Variant::Hdc2080);
+    assert!(sensor.is_ok());
+}
+
+#[test]
+fn temperature_only_leaves_humidity_untouched() {
+    let sim = Simulator::new(SlaveAddr::default());
+    let mut sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    sensor
+        .set_measurement_mode(MeasurementMode::TemperatureOnly)
+        .unwrap();
+    let data = sensor.measure(&mut sim.delay()).unwrap();
+    assert_near(25.0, data.temperature, 0.01);
+    assert!(data.humidity.is_none());
+    assert_eq!(0, sim.register(0x02));
+    assert_eq!(0, sim.register(0x03));
+}
+
+#[test]
+fn resolution_reduces_precision_and_conversion_time() {
+    let sim = Simulator::new(SlaveAddr::default());
+    sim.set_temperature(21.37);
+    let mut sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    sensor
+        .set_temperature_resolution(Resolution::Bits9)
+        .unwrap();
+    sensor.set_humidity_resolution(Resolution::Bits9).unwrap();
+    let data = sensor.measure(&mut sim.delay()).unwrap();
+    assert_eq!(0, sim.register(0x00) & 0x7F);
+    assert_eq!(0, sim.register(0x02) & 0x7F);
+    assert_near(21.37, data.temperature, 165.0 / 512.0);
+    assert_eq!(u64::from(sensor.conversion_time_us()), sim.now_us());
+}
+
+#[test]
+fn applies_offsets() {
+    let sim = Simulator::new(SlaveAddr::default());
+    sim.set_temperature(20.0);
+    sim.set_humidity(50.0);
+    let mut sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    sensor.set_temperature_offset(-1.0).unwrap();
+    sensor.set_humidity_offset(2.0).unwrap();
+    let expected_temperature = 20.0 + sensor.temperature_offset().unwrap();
+    let expected_humidity = 50.0 + sensor.humidity_offset().unwrap();
+    let data = sensor.measure(&mut sim.delay()).unwrap();
+    assert_near(expected_temperature, data.temperature, 0.01);
+    assert_near(expected_humidity, data.humidity.unwrap(), 0.01);
+}
+
+#[test]
+fn reports_thresholds_and_drives_interrupt_pin() {
+    let sim = Simulator::new(SlaveAddr::default());
+    sim.set_temperature(30.0);
+    sim.set_humidity(20.0);
+    let mut sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    sensor.set_temperature_threshold_high(28.0).unwrap();
+    sensor.set_humidity_threshold_low(30.0).unwrap();
+    assert_eq!(None, sim.interrupt_pin_level());
+    sensor
+        .set_interrupt_config(InterruptConfig {
+            high_temperature: true,
+            polarity: InterruptPolarity::ActiveHigh,
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(Some(false), sim.interrupt_pin_level());
+    sensor.read().expect_err("should block");
+    sim.advance_ms(2);
+    assert_eq!(Some(true), sim.interrupt_pin_level());
+    let status = sensor.status().unwrap();
+    assert!(status.data_ready);
+    assert!(status.high_temp_threshold_exceeded);
+    assert!(!status.low_temp_threshold_exceeded);
+    assert!(!status.high_humidity_threshold_exceeded);
+    assert!(status.low_humidity_threshold_exceeded);
+    assert_eq!(Some(false), sim.interrupt_pin_level());
+}
+
+#[test]
+fn can_read_with_drdy_pin() {
+    let sim = Simulator::new(SlaveAddr::default());
+    let mut sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    sensor
+        .set_interrupt_config(InterruptConfig {
+            data_ready: true,
+            ..Default::default()
+        })
+        .unwrap();
+    let mut pin = sim.interrupt_pin();
+    sensor
+        .read_with_drdy_pin(&mut pin)
+        .expect_err("should block");
+    sensor
+        .read_with_drdy_pin(&mut pin)
+        .expect_err("should block");
+    sim.advance_ms(2);
+    let data = sensor.read_with_drdy_pin(&mut pin).unwrap();
+    assert!(data.status.data_ready);
+    assert_eq!(Some(true), sim.interrupt_pin_level());
+}
+
+#[test]
+fn threshold_interrupt_does_not_produce_drdy_pin_measurement() {
+    let sim = Simulator::new(SlaveAddr::default());
+    sim.set_temperature(30.0);
+    let mut sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    sensor.set_temperature_threshold_high(28.0).unwrap();
+    sensor
+        .set_interrupt_config(InterruptConfig {
+            data_ready: true,
+            high_temperature: true,
+            mode: InterruptMode::Comparator,
+            ..Default::default()
+        })
+        .unwrap();
+    let mut pin = sim.interrupt_pin();
+    sensor.measure(&mut sim.delay()).unwrap();
+    assert_eq!(Some(false), sim.interrupt_pin_level());
+    sensor
+        .read_with_drdy_pin(&mut pin)
+        .expect_err("should block");
+    sensor
+        .read_with_drdy_pin(&mut pin)
+        .expect_err("should block");
+    sim.advance_ms(2);
+    let data = sensor.read_with_drdy_pin(&mut pin).unwrap();
+    assert!(data.status.data_ready);
+    assert!(data.status.high_temp_threshold_exceeded);
+}
+
+#[test]
+fn tracks_peaks_until_reset() {
+    let sim = Simulator::new(SlaveAddr::default());
+    let mut sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    sim.set_temperature(35.0);
+    sim.set_humidity(70.0);
+    sensor.measure(&mut sim.delay()).unwrap();
+    sim.set_temperature(20.0);
+    sim.set_humidity(40.0);
+    sensor.measure(&mut sim.delay()).unwrap();
+    assert_near(35.0, sensor.max_temperature().unwrap(), 165.0 / 256.0);
+    assert_near(70.0, sensor.max_humidity().unwrap(), 100.0 / 256.0);
+    assert_near(35.0, sensor.max_temperature().unwrap(), 165.0 / 256.0);
+    assert_near(70.0, sensor.max_humidity().unwrap(), 100.0 / 256.0);
+    sensor.software_reset(&mut sim.delay()).unwrap();
+    assert_eq!(0, sim.register(0x05));
+    assert_eq!(0, sim.register(0x06));
+}
+
+#[test]
+fn measures_continuously() {
+    let sim = Simulator::new(SlaveAddr::default());
+    let sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    let mut sensor = sensor.into_continuous(SamplingRate::Hz5).ok().unwrap();
+    sim.advance_ms(2);
+    assert!(sensor.read().unwrap().status.data_ready);
+    assert!(!sensor.read().unwrap().status.data_ready);
+    sim.set_temperature(30.0);
+    sim.advance_ms(198);
+    assert!(!sensor.read().unwrap().status.data_ready);
+    sim.advance_ms(2);
+    let data = sensor.read().unwrap();
+    assert!(data.status.data_ready);
+    assert_near(30.0, data.temperature, 0.01);
+    let mut sensor = sensor.into_one_shot().ok().unwrap();
+    sim.advance_ms(1000);
+    assert!(!sensor.status().unwrap().data_ready);
+}
+
+#[test]
+fn software_reset_restores_power_on_state() {
+    let sim = Simulator::new(SlaveAddr::default());
+    let sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    let mut sensor = sensor.into_continuous(SamplingRate::Hz1).ok().unwrap();
+    sensor.set_temperature_offset(1.0).unwrap();
+    let mut sensor = sensor.into_one_shot().ok().unwrap();
+    sensor.software_reset(&mut sim.delay()).unwrap();
+    assert_eq!(
+        DeviceConfiguration::default(),
+        sensor.read_configuration().unwrap()
+    );
+    sim.advance_ms(2000);
+    assert!(!sim.is_converting());
+}
+
+#[test]
+fn can_restore_configuration() {
+    let sim = Simulator::new(SlaveAddr::default());
+    let mut sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    sensor.set_humidity_offset(-3.0).unwrap();
+    sensor.set_temperature_threshold_low(5.0).unwrap();
+    sensor
+        .set_measurement_mode(MeasurementMode::TemperatureOnly)
+        .unwrap();
+    let config = sensor.read_configuration().unwrap();
+    sensor.software_reset(&mut sim.delay()).unwrap();
+    sensor.apply_configuration(&config).unwrap();
+    assert_eq!(config, sensor.read_configuration().unwrap());
+    assert!(sensor.measure(&mut sim.delay()).unwrap().humidity.is_none());
+}
+
+#[test]
+fn can_continue_with_loaded_configuration() {
+    let sim = Simulator::new(SlaveAddr::default());
+    let mut sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    sensor
+        .set_measurement_mode(MeasurementMode::TemperatureOnly)
+        .unwrap();
+    let registers: Vec<u8> = (0..16).map(|register| sim.register(register)).collect();
+    let mut sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    sensor.load_configuration().unwrap();
+    let after: Vec<u8> = (0..16).map(|register| sim.register(register)).collect();
+    assert_eq!(registers, after);
+    assert!(sensor.measure(&mut sim.delay()).unwrap().humidity.is_none());
+}
+
+#[test]
+fn loaded_configuration_stops_auto_measurement_on_write() {
+    let sim = Simulator::new(SlaveAddr::default());
+    let sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    let _sensor = sensor.into_continuous(SamplingRate::Hz5).ok().unwrap();
+    let mut sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    sensor.load_configuration().unwrap();
+    sim.advance_ms(2);
+    assert!(sensor.status().unwrap().data_ready);
+    sensor.enable_heater().unwrap();
+    assert_eq!(0x08, sim.register(0x0E));
+    sim.advance_ms(1000);
+    assert!(!sensor.status().unwrap().data_ready);
+}
+
+#[test]
+fn ignores_writes_to_read_only_registers() {
+    let sim = Simulator::new(SlaveAddr::default());
+    let mut i2c = sim.i2c();
+    i2c.write(0x40, &[0x00, 0x12, 0x34, 0x56, 0x78, 0x9A])
+        .unwrap();
+    i2c.write(0x40, &[0xFC, 0x00]).unwrap();
+    let mut data = [0; 5];
+    i2c.write_read(0x40, &[0x00], &mut data).unwrap();
+    assert_eq!([0; 5], data);
+    let mut ids = [0; 4];
+    i2c.write_read(0x40, &[0xFC], &mut ids).unwrap();
+    assert_eq!([0x49, 0x54, 0xD0, 0x07], ids);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn can_measure_asynchronously() {
+    let sim = Simulator::new(SlaveAddr::default());
+    sim.set_temperature(-10.0);
+    let mut sensor = hdc20xx::AsyncHdc20xx::new(sim.i2c(), SlaveAddr::default());
+    let data = embassy_futures::block_on(sensor.measure(&mut sim.delay())).unwrap();
+    assert_near(-10.0, data.temperature, 0.01);
+}
+
+#[test]
+fn can_dump_registers() {
+    let sim = Simulator::new(SlaveAddr::default());
+    let mut sensor = Hdc20xx::new(sim.i2c(), SlaveAddr::default());
+    sensor.set_temperature_threshold_low(10.0).unwrap();
+    sensor.measure(&mut sim.delay()).unwrap();
+    let expected: Vec<u8> = (0..16).map(|register| sim.register(register)).collect();
+    sensor.enable_peak_readout();
+    let config = sensor.read_configuration().unwrap();
+    let dump = sensor.dump_registers().unwrap();
+    assert_eq!(expected[0x05..], dump.registers[0x05..]);
+    assert_eq!(0x5449, dump.manufacturer_id);
+    assert_eq!(0x07D0, dump.device_id);
+    assert!(config.temperature_max.unwrap() > 0);
+    assert_eq!(config, dump.configuration());
+}
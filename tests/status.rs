@@ -1,6 +1,6 @@
 mod common;
 use crate::common::{destroy, new, BitFlags as BF, Register, BASE_ADDR};
-use embedded_hal_mock::i2c::Transaction as I2cTrans;
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
 use hdc20xx::Status;
 
 macro_rules! test {
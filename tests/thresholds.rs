@@ -0,0 +1,118 @@
+mod common;
+use crate::common::{destroy, new, Register, BASE_ADDR};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+use hdc20xx::Error;
+
+macro_rules! set_threshold_test {
+    ($name:ident, $method:ident, $reg:ident, $value:expr, $arg:expr) => {
+        #[test]
+        fn $name() {
+            let mut sensor = new(&[I2cTrans::write(BASE_ADDR, vec![Register::$reg, $value])]);
+            sensor.$method($arg).unwrap();
+            destroy(sensor);
+        }
+    };
+}
+
+set_threshold_test!(
+    temp_low_min,
+    set_temperature_threshold_low,
+    TEMP_THR_L,
+    0,
+    -40.0
+);
+set_threshold_test!(
+    temp_low_25,
+    set_temperature_threshold_low,
+    TEMP_THR_L,
+    101,
+    25.0
+);
+set_threshold_test!(
+    temp_high_max,
+    set_temperature_threshold_high,
+    TEMP_THR_H,
+    255,
+    124.35
+);
+set_threshold_test!(
+    temp_high_0,
+    set_temperature_threshold_high,
+    TEMP_THR_H,
+    62,
+    0.0
+);
+set_threshold_test!(rh_low_min, set_humidity_threshold_low, RH_THR_L, 0, 0.0);
+set_threshold_test!(rh_low_50, set_humidity_threshold_low, RH_THR_L, 128, 50.0);
+set_threshold_test!(
+    rh_high_max,
+    set_humidity_threshold_high,
+    RH_THR_H,
+    255,
+    99.6
+);
+set_threshold_test!(rh_high_80, set_humidity_threshold_high, RH_THR_H, 205, 80.0);
+
+macro_rules! invalid_threshold_test {
+    ($name:ident, $method:ident, $arg:expr) => {
+        #[test]
+        fn $name() {
+            let mut sensor = new(&[]);
+            match sensor.$method($arg) {
+                Err(Error::InvalidInputData) => (),
+                _ => panic!("InvalidInputData not returned."),
+            }
+            destroy(sensor);
+        }
+    };
+}
+
+invalid_threshold_test!(
+    cannot_set_temp_low_too_low,
+    set_temperature_threshold_low,
+    -40.5
+);
+invalid_threshold_test!(
+    cannot_set_temp_high_too_high,
+    set_temperature_threshold_high,
+    125.0
+);
+invalid_threshold_test!(cannot_set_rh_low_negative, set_humidity_threshold_low, -1.0);
+invalid_threshold_test!(
+    cannot_set_rh_high_too_high,
+    set_humidity_threshold_high,
+    100.0
+);
+
+macro_rules! get_threshold_test {
+    ($name:ident, $method:ident, $reg:ident, $value:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let mut sensor = new(&[I2cTrans::write_read(
+                BASE_ADDR,
+                vec![Register::$reg],
+                vec![$value],
+            )]);
+            let value = sensor.$method().unwrap();
+            assert!((value - $expected).abs() < 0.01);
+            destroy(sensor);
+        }
+    };
+}
+
+get_threshold_test!(
+    get_temp_low,
+    temperature_threshold_low,
+    TEMP_THR_L,
+    0,
+    -40.0
+);
+get_threshold_test!(
+    get_temp_high,
+    temperature_threshold_high,
+    TEMP_THR_H,
+    128,
+    42.5
+);
+get_threshold_test!(get_rh_low, humidity_threshold_low, RH_THR_L, 64, 25.0);
+get_threshold_test!(get_rh_high, humidity_threshold_high, RH_THR_H, 192, 75.0);
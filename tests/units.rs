@@ -0,0 +1,28 @@
+mod common;
+use crate::common::measurement;
+use hdc20xx::{Celsius, RelativeHumidity};
+
+#[test]
+fn can_get_typed_values() {
+    let data = measurement(25.0, Some(40.0));
+    assert_eq!(Celsius(25.0), data.temperature_celsius());
+    assert_eq!(77.0, data.temperature_celsius().fahrenheit());
+    assert_eq!(Some(RelativeHumidity(40.0)), data.relative_humidity());
+}
+
+#[test]
+fn typed_humidity_is_none_for_temp_only() {
+    assert_eq!(None, measurement(25.0, None).relative_humidity());
+}
+
+#[cfg(feature = "uom")]
+#[test]
+fn can_get_uom_quantities() {
+    use uom::si::{ratio::ratio, thermodynamic_temperature::kelvin};
+    let data = measurement(25.0, Some(40.0));
+    let temperature = data.thermodynamic_temperature().get::<kelvin>();
+    assert!((temperature - 298.15).abs() < 0.001);
+    let humidity = data.humidity_ratio().unwrap().get::<ratio>();
+    assert!((humidity - 0.4).abs() < 0.000_001);
+    assert!(measurement(25.0, None).humidity_ratio().is_none());
+}